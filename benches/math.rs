@@ -14,6 +14,13 @@ fn decimal64_benchmark(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("ln_fast", |b| {
+        b.iter(|| {
+            let out = black_box(ln_input).ln_fast().unwrap();
+            black_box(out);
+        })
+    });
+
     group.bench_function("ln_via_f64", |b| {
         b.iter(|| {
             let value = black_box(ln_input).to_f64();
@@ -29,6 +36,13 @@ fn decimal64_benchmark(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("exp_fast", |b| {
+        b.iter(|| {
+            let out = black_box(exp_input).exp_fast().unwrap();
+            black_box(out);
+        })
+    });
+
     group.bench_function("exp_via_f64", |b| {
         b.iter(|| {
             let value = black_box(exp_input).to_f64();
@@ -1,15 +1,68 @@
-use crate::{DecimalU64, ScaleMetrics};
-use std::iter::Sum;
-use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+use crate::error::Error;
+use crate::macros::strict_op;
+use crate::round::{Floor, RoundingPolicy, div_rounded, round_up};
+use crate::wide::{wide_div, widening_mul};
+use crate::{
+    DecimalU64, SCALE_FACTORS, ScaleMetrics, U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16,
+    U17, U18,
+};
+use std::fmt::{Display, Formatter};
+use std::iter::{Product, Sum};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+/// Resolves the scale that results from adding or subtracting decimals of scales `Self` and `B` with
+/// [`DecimalU64::add_to`] and [`DecimalU64::sub_to`], which is the larger of the two so that no digits
+/// are lost.
+///
+/// Implemented for every pair of built-in scales and for any scale paired with itself. Downstream crates
+/// can implement it for their own pairs of scales.
+pub trait MaxScale<B: ScaleMetrics>: ScaleMetrics {
+    type Output: ScaleMetrics;
+}
+
+impl<S: ScaleMetrics> MaxScale<S> for S {
+    type Output = S;
+}
+
+macro_rules! gen_max_scale {
+    () => {};
+    ($lower:ident $($higher:ident)*) => {
+        $(
+            impl MaxScale<$higher> for $lower {
+                type Output = $higher;
+            }
+
+            impl MaxScale<$lower> for $higher {
+                type Output = $higher;
+            }
+        )*
+        gen_max_scale!($($higher)*);
+    };
+}
+
+gen_max_scale!(U0 U1 U2 U3 U4 U5 U6 U7 U8 U9 U10 U11 U12 U13 U14 U15 U16 U17 U18);
+
+/// Factor that brings a value of scale `S` up to scale `T`, which must not be lower.
+#[inline]
+const fn upscale_factor<S: ScaleMetrics, T: ScaleMetrics>() -> u64 {
+    SCALE_FACTORS[(T::SCALE - S::SCALE) as usize]
+}
 
 impl<S: ScaleMetrics> Mul for DecimalU64<S> {
     type Output = DecimalU64<S>;
 
     #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        let product = self.0 as u128 * rhs.0 as u128;
-        let scale_factor = S::SCALE_FACTOR as u128;
-        Self::new((product / scale_factor) as u64)
+        strict_op!(
+            self.checked_mul(rhs),
+            {
+                let product = self.0 as u128 * rhs.0 as u128;
+                let scale_factor = S::SCALE_FACTOR as u128;
+                Self::new((product / scale_factor) as u64)
+            },
+            "attempt to multiply with overflow"
+        )
     }
 }
 
@@ -18,8 +71,7 @@ impl<S: ScaleMetrics> Add for DecimalU64<S> {
 
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
-        let sum = self.0 + rhs.0;
-        Self::new(sum)
+        strict_op!(self.checked_add(rhs), Self::new(self.0 + rhs.0), "attempt to add with overflow")
     }
 }
 
@@ -28,8 +80,7 @@ impl<S: ScaleMetrics> Sub for DecimalU64<S> {
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        let diff = self.0 - rhs.0;
-        Self::new(diff)
+        strict_op!(self.checked_sub(rhs), Self::new(self.0 - rhs.0), "attempt to subtract with overflow")
     }
 }
 
@@ -41,46 +92,187 @@ impl<S: ScaleMetrics> Div for DecimalU64<S> {
         if rhs.0 == 0 {
             panic!("Division by zero");
         }
-        let dividend = self.0 as u128 * S::SCALE_FACTOR as u128;
-        let quotient = dividend / (rhs.0 as u128);
-        Self::new(quotient as u64)
+        strict_op!(
+            self.checked_div(rhs),
+            {
+                let dividend = self.0 as u128 * S::SCALE_FACTOR as u128;
+                let quotient = dividend / (rhs.0 as u128);
+                Self::new(quotient as u64)
+            },
+            "attempt to divide with overflow"
+        )
     }
 }
 
-impl<S: ScaleMetrics> AddAssign for DecimalU64<S> {
+impl<S: ScaleMetrics> Rem for DecimalU64<S> {
+    type Output = DecimalU64<S>;
+
     #[inline]
-    fn add_assign(&mut self, rhs: DecimalU64<S>) {
-        self.0 += rhs.0;
+    fn rem(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0 {
+            panic!("Division by zero");
+        }
+        Self::new(self.0 % rhs.0)
+    }
+}
+
+impl<S: ScaleMetrics> RemAssign for DecimalU64<S> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        if rhs.0 == 0 {
+            panic!("Division by zero");
+        }
+        self.0 %= rhs.0;
     }
 }
 
-impl<'a, S: ScaleMetrics> AddAssign<&'a DecimalU64<S>> for DecimalU64<S> {
-    fn add_assign(&mut self, rhs: &'a DecimalU64<S>) {
-        self.0 += rhs.0;
+// Scalar multiplication and division by an integer count, applied directly to the unscaled value. Only
+// `u64` is supported, so that an unsuffixed literal such as `price * 3` is unambiguous.
+impl<S: ScaleMetrics> Mul<u64> for DecimalU64<S> {
+    type Output = DecimalU64<S>;
+
+    #[inline]
+    fn mul(self, rhs: u64) -> Self::Output {
+        strict_op!(self.checked_mul_int(rhs), Self::new(self.0 * rhs), "attempt to multiply with overflow")
     }
 }
 
-impl<S: ScaleMetrics> AddAssign<DecimalU64<S>> for &mut DecimalU64<S> {
+impl<S: ScaleMetrics> Mul<DecimalU64<S>> for u64 {
+    type Output = DecimalU64<S>;
+
     #[inline]
-    fn add_assign(&mut self, rhs: DecimalU64<S>) {
-        self.0 += rhs.0;
+    fn mul(self, rhs: DecimalU64<S>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<S: ScaleMetrics> Div<u64> for DecimalU64<S> {
+    type Output = DecimalU64<S>;
+
+    #[inline]
+    fn div(self, rhs: u64) -> Self::Output {
+        if rhs == 0 {
+            panic!("Division by zero");
+        }
+        Self::new(self.0 / rhs)
     }
 }
 
-impl<'a, S: ScaleMetrics> AddAssign<&'a DecimalU64<S>> for &'a mut DecimalU64<S> {
+impl<S: ScaleMetrics> MulAssign<u64> for DecimalU64<S> {
     #[inline]
-    fn add_assign(&mut self, rhs: &'a DecimalU64<S>) {
-        self.0 += rhs.0;
+    fn mul_assign(&mut self, rhs: u64) {
+        self.0 = strict_op!(self.0.checked_mul(rhs), self.0 * rhs, "attempt to multiply with overflow");
+    }
+}
+
+impl<S: ScaleMetrics> DivAssign<u64> for DecimalU64<S> {
+    #[inline]
+    fn div_assign(&mut self, rhs: u64) {
+        if rhs == 0 {
+            panic!("Division by zero");
+        }
+        self.0 /= rhs;
+    }
+}
+
+/// Forwards the borrowed variants of a binary operator to its owned implementation.
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident) => {
+        impl<'a, S: ScaleMetrics> $imp<DecimalU64<S>> for &'a DecimalU64<S> {
+            type Output = DecimalU64<S>;
+
+            #[inline]
+            fn $method(self, rhs: DecimalU64<S>) -> Self::Output {
+                $imp::$method(DecimalU64::<S>::new(self.0), rhs)
+            }
+        }
+
+        impl<'a, S: ScaleMetrics> $imp<&'a DecimalU64<S>> for DecimalU64<S> {
+            type Output = DecimalU64<S>;
+
+            #[inline]
+            fn $method(self, rhs: &'a DecimalU64<S>) -> Self::Output {
+                $imp::$method(self, DecimalU64::<S>::new(rhs.0))
+            }
+        }
+
+        impl<'a, 'b, S: ScaleMetrics> $imp<&'a DecimalU64<S>> for &'b DecimalU64<S> {
+            type Output = DecimalU64<S>;
+
+            #[inline]
+            fn $method(self, rhs: &'a DecimalU64<S>) -> Self::Output {
+                $imp::$method(DecimalU64::<S>::new(self.0), DecimalU64::<S>::new(rhs.0))
+            }
+        }
+    };
+}
+
+forward_ref_binop!(impl Add, add);
+forward_ref_binop!(impl Sub, sub);
+forward_ref_binop!(impl Mul, mul);
+forward_ref_binop!(impl Div, div);
+forward_ref_binop!(impl Rem, rem);
+
+impl<S: ScaleMetrics> AddAssign for DecimalU64<S> {
+    #[inline]
+    fn add_assign(&mut self, rhs: DecimalU64<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0), self.0 + rhs.0, "attempt to add with overflow");
     }
 }
 
 impl<S: ScaleMetrics> SubAssign for DecimalU64<S> {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
-        self.0 -= rhs.0;
+        self.0 = strict_op!(self.0.checked_sub(rhs.0), self.0 - rhs.0, "attempt to subtract with overflow");
     }
 }
 
+impl<S: ScaleMetrics> MulAssign for DecimalU64<S> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = DecimalU64::new(self.0) * rhs;
+    }
+}
+
+impl<S: ScaleMetrics> DivAssign for DecimalU64<S> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = DecimalU64::new(self.0) / rhs;
+    }
+}
+
+/// Forwards the borrowed variants of a compound assignment operator to its owned implementation.
+macro_rules! forward_ref_op_assign {
+    (impl $imp:ident, $method:ident) => {
+        impl<'a, S: ScaleMetrics> $imp<&'a DecimalU64<S>> for DecimalU64<S> {
+            #[inline]
+            fn $method(&mut self, rhs: &'a DecimalU64<S>) {
+                $imp::$method(self, DecimalU64::<S>::new(rhs.0));
+            }
+        }
+
+        impl<S: ScaleMetrics> $imp<DecimalU64<S>> for &mut DecimalU64<S> {
+            #[inline]
+            fn $method(&mut self, rhs: DecimalU64<S>) {
+                $imp::$method(&mut **self, rhs);
+            }
+        }
+
+        impl<'a, S: ScaleMetrics> $imp<&'a DecimalU64<S>> for &mut DecimalU64<S> {
+            #[inline]
+            fn $method(&mut self, rhs: &'a DecimalU64<S>) {
+                $imp::$method(&mut **self, DecimalU64::<S>::new(rhs.0));
+            }
+        }
+    };
+}
+
+forward_ref_op_assign!(impl AddAssign, add_assign);
+forward_ref_op_assign!(impl SubAssign, sub_assign);
+forward_ref_op_assign!(impl MulAssign, mul_assign);
+forward_ref_op_assign!(impl DivAssign, div_assign);
+forward_ref_op_assign!(impl RemAssign, rem_assign);
+
 impl<S: ScaleMetrics> Sum for DecimalU64<S> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         let mut sum = Self::ZERO;
@@ -101,6 +293,38 @@ impl<'a, S: ScaleMetrics> Sum<&'a DecimalU64<S>> for DecimalU64<S> {
     }
 }
 
+impl<'a, S: ScaleMetrics> Sum<&'a mut DecimalU64<S>> for DecimalU64<S> {
+    fn sum<I: Iterator<Item = &'a mut DecimalU64<S>>>(iter: I) -> Self {
+        iter.map(|i| &*i).sum()
+    }
+}
+
+impl<S: ScaleMetrics> Product for DecimalU64<S> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut product = Self::ONE;
+        for i in iter {
+            product *= i;
+        }
+        product
+    }
+}
+
+impl<'a, S: ScaleMetrics> Product<&'a DecimalU64<S>> for DecimalU64<S> {
+    fn product<I: Iterator<Item = &'a DecimalU64<S>>>(iter: I) -> Self {
+        let mut product = Self::ONE;
+        for i in iter {
+            product *= i;
+        }
+        product
+    }
+}
+
+impl<'a, S: ScaleMetrics> Product<&'a mut DecimalU64<S>> for DecimalU64<S> {
+    fn product<I: Iterator<Item = &'a mut DecimalU64<S>>>(iter: I) -> Self {
+        iter.map(|i| &*i).product()
+    }
+}
+
 impl<S: ScaleMetrics> DecimalU64<S> {
     /// Multiply two decimals with the same scale.
     /// This performs the multiplication in u128 and then scales the result down by dividing by `S::SCALE_FACTOR`.
@@ -129,34 +353,786 @@ impl<S: ScaleMetrics> DecimalU64<S> {
         Some(Self::new(sum))
     }
 
-    /// Subtract one decimal from another. Returns an error if underflow occurs.
+    /// Subtract one decimal from another. Returns `None` if underflow occurs.
     #[inline]
     pub fn checked_sub(self, other: Self) -> Option<Self> {
         let diff = self.0.checked_sub(other.0)?;
         Some(Self::new(diff))
     }
 
-    /// Divide one decimal by another using 128-bit arithmetic for the intermediate computation.
-    /// The result is computed as (self.unscaled * SCALE_FACTOR) / other.unscaled.
+    /// Divide one decimal by another using 128-bit arithmetic for the intermediate computation.
+    /// The result is computed as (self.unscaled * SCALE_FACTOR) / other.unscaled.
+    #[inline]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0 == 0 {
+            return None;
+        }
+        let dividend = (self.0 as u128).checked_mul(S::SCALE_FACTOR as u128)?;
+        let quotient = dividend / (other.0 as u128);
+        if quotient > u64::MAX as u128 {
+            None
+        } else {
+            Some(Self::new(quotient as u64))
+        }
+    }
+
+    /// Multiply two decimals with the same scale, resolving the digits dropped from the 128-bit product
+    /// with `R` instead of truncating them. Returns `None` if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::HalfUp;
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// let notional = DecimalU64::<U2>::from_str("10.05").unwrap();
+    /// let fee_rate = DecimalU64::<U2>::from_str("0.15").unwrap();
+    /// assert_eq!("1.51", notional.checked_mul_round::<HalfUp>(fee_rate).unwrap().to_string());
+    /// assert_eq!("1.50", (notional * fee_rate).to_string());
+    /// ```
+    #[inline]
+    pub const fn checked_mul_round<R: RoundingPolicy>(self, other: Self) -> Option<Self> {
+        let product = self.0 as u128 * other.0 as u128;
+        let result = div_rounded(product, S::SCALE_FACTOR as u128, R::MODE);
+        if result > u64::MAX as u128 {
+            None
+        } else {
+            Some(Self::new(result as u64))
+        }
+    }
+
+    /// Multiply two decimals with the same scale, resolving the dropped digits with `R`. Panics if the
+    /// result does not fit.
+    #[inline]
+    pub const fn mul_round<R: RoundingPolicy>(self, other: Self) -> Self {
+        match self.checked_mul_round::<R>(other) {
+            Some(value) => value,
+            None => panic!("attempt to multiply with overflow"),
+        }
+    }
+
+    /// Divide one decimal by another with the same scale, resolving the digits dropped from the quotient
+    /// with `R` instead of truncating them. Returns `None` on division by zero or if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::{Ceil, HalfUp};
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// let two = DecimalU64::<U2>::TWO;
+    /// let three = DecimalU64::<U2>::THREE;
+    /// assert_eq!("0.67", two.checked_div_round::<HalfUp>(three).unwrap().to_string());
+    /// assert_eq!("0.34", DecimalU64::<U2>::ONE.checked_div_round::<Ceil>(three).unwrap().to_string());
+    /// ```
+    #[inline]
+    pub const fn checked_div_round<R: RoundingPolicy>(self, other: Self) -> Option<Self> {
+        if other.0 == 0 {
+            return None;
+        }
+        // u64 * 10^19 always fits into u128
+        let dividend = self.0 as u128 * S::SCALE_FACTOR as u128;
+        let result = div_rounded(dividend, other.0 as u128, R::MODE);
+        if result > u64::MAX as u128 {
+            None
+        } else {
+            Some(Self::new(result as u64))
+        }
+    }
+
+    /// Divide one decimal by another with the same scale, resolving the dropped digits with `R`. Panics
+    /// on division by zero or if the result does not fit.
+    #[inline]
+    pub const fn div_round<R: RoundingPolicy>(self, other: Self) -> Self {
+        if other.0 == 0 {
+            panic!("Division by zero");
+        }
+        match self.checked_div_round::<R>(other) {
+            Some(value) => value,
+            None => panic!("attempt to divide with overflow"),
+        }
+    }
+
+    /// Add two decimals of any scale, returning the sum at the larger of both scales. Returns `None` if
+    /// the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2, U8};
+    ///
+    /// let price = DecimalU64::<U2>::from_str("101.25").unwrap();
+    /// let offset = DecimalU64::<U8>::from_str("0.00000001").unwrap();
+    /// assert_eq!("101.25000001", price.checked_add_to(offset).unwrap().to_string());
+    /// ```
+    #[inline]
+    pub const fn checked_add_to<B: ScaleMetrics>(self, other: DecimalU64<B>) -> Option<DecimalU64<S::Output>>
+    where
+        S: MaxScale<B>,
+    {
+        let (lhs, rhs) = match Self::try_align(self, other) {
+            Ok(aligned) => aligned,
+            Err(_) => return None,
+        };
+        match lhs.checked_add(rhs) {
+            Some(sum) => Some(DecimalU64::new(sum)),
+            None => None,
+        }
+    }
+
+    /// Add two decimals of any scale, returning the sum at the larger of both scales. Panics if the result
+    /// does not fit.
+    #[inline]
+    pub const fn add_to<B: ScaleMetrics>(self, other: DecimalU64<B>) -> DecimalU64<S::Output>
+    where
+        S: MaxScale<B>,
+    {
+        match self.checked_add_to(other) {
+            Some(value) => value,
+            None => panic!("attempt to add with overflow"),
+        }
+    }
+
+    /// Subtract a decimal of any scale from this one, returning the difference at the larger of both
+    /// scales. Returns `None` if the result would be negative or does not fit.
+    #[inline]
+    pub const fn checked_sub_to<B: ScaleMetrics>(self, other: DecimalU64<B>) -> Option<DecimalU64<S::Output>>
+    where
+        S: MaxScale<B>,
+    {
+        let (lhs, rhs) = match Self::try_align(self, other) {
+            Ok(aligned) => aligned,
+            Err(_) => return None,
+        };
+        match lhs.checked_sub(rhs) {
+            Some(diff) => Some(DecimalU64::new(diff)),
+            None => None,
+        }
+    }
+
+    /// Subtract a decimal of any scale from this one, returning the difference at the larger of both
+    /// scales. Panics if the result would be negative or does not fit.
+    #[inline]
+    pub const fn sub_to<B: ScaleMetrics>(self, other: DecimalU64<B>) -> DecimalU64<S::Output>
+    where
+        S: MaxScale<B>,
+    {
+        match self.checked_sub_to(other) {
+            Some(value) => value,
+            None => panic!("attempt to subtract with overflow"),
+        }
+    }
+
+    /// Multiply two decimals of any scale, returning the product at scale `T` with the dropped digits
+    /// resolved by `R`. Returns `None` if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::HalfUp;
+    /// use decimal64::{DecimalU64, U2, U4, U8};
+    ///
+    /// let price = DecimalU64::<U2>::from_str("101.25").unwrap();
+    /// let quantity = DecimalU64::<U8>::from_str("0.00012345").unwrap();
+    /// let notional = price.checked_mul_to::<U4, HalfUp, _>(quantity).unwrap();
+    /// assert_eq!("0.0125", notional.to_string());
+    /// ```
+    #[inline]
+    pub const fn checked_mul_to<T: ScaleMetrics, R: RoundingPolicy, B: ScaleMetrics>(
+        self,
+        other: DecimalU64<B>,
+    ) -> Option<DecimalU64<T>> {
+        // the exact product has at most 38 fractional digits and always fits into u128
+        let product = self.0 as u128 * other.0 as u128;
+        let product_scale = S::SCALE + B::SCALE;
+        let result = if T::SCALE >= product_scale {
+            match product.checked_mul(SCALE_FACTORS[(T::SCALE - product_scale) as usize] as u128) {
+                Some(value) => value,
+                None => return None,
+            }
+        } else {
+            div_rounded(product, 10u128.pow((product_scale - T::SCALE) as u32), R::MODE)
+        };
+        if result > u64::MAX as u128 {
+            None
+        } else {
+            Some(DecimalU64::new(result as u64))
+        }
+    }
+
+    /// Multiply two decimals of any scale, returning the product at scale `T` with the dropped digits
+    /// resolved by `R`. Panics if the result does not fit.
+    #[inline]
+    pub const fn mul_to<T: ScaleMetrics, R: RoundingPolicy, B: ScaleMetrics>(
+        self,
+        other: DecimalU64<B>,
+    ) -> DecimalU64<T> {
+        match self.checked_mul_to::<T, R, B>(other) {
+            Some(value) => value,
+            None => panic!("attempt to multiply with overflow"),
+        }
+    }
+
+    /// Divide one decimal by another of any scale, returning the quotient at scale `T` with the dropped
+    /// digits resolved by `R`. Returns `None` on division by zero or if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::{Ceil, Floor};
+    /// use decimal64::{DecimalU64, U0, U2, U8};
+    ///
+    /// let notional = DecimalU64::<U2>::from_str("100").unwrap();
+    /// let price = DecimalU64::<U8>::from_str("3").unwrap();
+    /// assert_eq!("33", notional.checked_div_to::<U0, Floor, _>(price).unwrap().to_string());
+    /// assert_eq!("34", notional.checked_div_to::<U0, Ceil, _>(price).unwrap().to_string());
+    /// ```
+    #[inline]
+    pub const fn checked_div_to<T: ScaleMetrics, R: RoundingPolicy, B: ScaleMetrics>(
+        self,
+        other: DecimalU64<B>,
+    ) -> Option<DecimalU64<T>> {
+        if other.0 == 0 {
+            return None;
+        }
+        // quotient = self * 10^(B + T - S) / other, widened to 256 bits when the exponent is positive
+        let (high, low, divisor) = if B::SCALE + T::SCALE >= S::SCALE {
+            let factor = 10u128.pow((B::SCALE + T::SCALE - S::SCALE) as u32);
+            let (high, low) = widening_mul(self.0 as u128, factor);
+            (high, low, other.0 as u128)
+        } else {
+            let factor = SCALE_FACTORS[(S::SCALE - B::SCALE - T::SCALE) as usize] as u128;
+            (0, self.0 as u128, other.0 as u128 * factor)
+        };
+        let (quotient, remainder) = match wide_div(high, low, divisor) {
+            Some(value) => value,
+            None => return None,
+        };
+        if quotient > u64::MAX as u128 {
+            return None;
+        }
+        let quotient = quotient as u64;
+        if round_up(R::MODE, quotient & 1 == 1, remainder, divisor, false) {
+            match quotient.checked_add(1) {
+                Some(value) => Some(DecimalU64::new(value)),
+                None => None,
+            }
+        } else {
+            Some(DecimalU64::new(quotient))
+        }
+    }
+
+    /// Divide one decimal by another of any scale, returning the quotient at scale `T` with the dropped
+    /// digits resolved by `R`. Panics on division by zero or if the result does not fit.
+    #[inline]
+    pub const fn div_to<T: ScaleMetrics, R: RoundingPolicy, B: ScaleMetrics>(
+        self,
+        other: DecimalU64<B>,
+    ) -> DecimalU64<T> {
+        if other.0 == 0 {
+            panic!("Division by zero");
+        }
+        match self.checked_div_to::<T, R, B>(other) {
+            Some(value) => value,
+            None => panic!("attempt to divide with overflow"),
+        }
+    }
+}
+
+/// Saturating, wrapping and overflowing counterparts of the same-scale operators, mirroring the std
+/// integer API. Multiplication and division keep the truncating 128-bit path of `Mul` and `Div`, so
+/// wrapping results are the low 64 bits of the truncated 128-bit result.
+impl<S: ScaleMetrics> DecimalU64<S> {
+    /// Add two decimals, returning the wrapped sum and whether an overflow occurred.
+    #[inline]
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (sum, overflow) = self.0.overflowing_add(other.0);
+        (Self::new(sum), overflow)
+    }
+
+    /// Subtract one decimal from another, returning the wrapped difference and whether an underflow
+    /// occurred.
+    #[inline]
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (diff, overflow) = self.0.overflowing_sub(other.0);
+        (Self::new(diff), overflow)
+    }
+
+    /// Multiply two decimals, returning the wrapped product and whether an overflow occurred.
+    #[inline]
+    pub const fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let product = self.0 as u128 * other.0 as u128 / S::SCALE_FACTOR as u128;
+        (Self::new(product as u64), product > u64::MAX as u128)
+    }
+
+    /// Divide one decimal by another, returning the wrapped quotient and whether an overflow occurred.
+    /// Panics on division by zero.
+    #[inline]
+    pub const fn overflowing_div(self, other: Self) -> (Self, bool) {
+        if other.0 == 0 {
+            panic!("Division by zero");
+        }
+        let quotient = self.0 as u128 * S::SCALE_FACTOR as u128 / other.0 as u128;
+        (Self::new(quotient as u64), quotient > u64::MAX as u128)
+    }
+
+    /// Add two decimals, wrapping around at `MAX`.
+    #[inline]
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Subtract one decimal from another, wrapping around at `ZERO`.
+    #[inline]
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Multiply two decimals, keeping the low 64 bits of the truncated product.
+    #[inline]
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        self.overflowing_mul(other).0
+    }
+
+    /// Divide one decimal by another, keeping the low 64 bits of the truncated quotient. Panics on
+    /// division by zero.
+    #[inline]
+    pub const fn wrapping_div(self, other: Self) -> Self {
+        self.overflowing_div(other).0
+    }
+
+    /// Add two decimals, saturating at `MAX`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// assert_eq!(DecimalU64::<U8>::MAX, DecimalU64::<U8>::MAX.saturating_add(DecimalU64::ONE));
+    /// assert_eq!(DecimalU64::<U8>::ZERO, DecimalU64::<U8>::ONE.saturating_sub(DecimalU64::TWO));
+    /// ```
+    #[inline]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self::new(self.0.saturating_add(other.0))
+    }
+
+    /// Subtract one decimal from another, saturating at `ZERO`.
+    #[inline]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self::new(self.0.saturating_sub(other.0))
+    }
+
+    /// Multiply two decimals, saturating at `MAX`.
+    #[inline]
+    pub const fn saturating_mul(self, other: Self) -> Self {
+        match self.overflowing_mul(other) {
+            (_, true) => Self::MAX,
+            (product, false) => product,
+        }
+    }
+
+    /// Divide one decimal by another, saturating at `MAX`. Panics on division by zero.
+    #[inline]
+    pub const fn saturating_div(self, other: Self) -> Self {
+        match self.overflowing_div(other) {
+            (_, true) => Self::MAX,
+            (quotient, false) => quotient,
+        }
+    }
+}
+
+/// Fallible arithmetic that reports why an operation failed, for use with `?` alongside the other
+/// `Result`-returning APIs of the crate.
+impl<S: ScaleMetrics> DecimalU64<S> {
+    /// Add two decimals with the same scale, returning [`Error::Overflow`] if the sum does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2, U8};
+    /// use decimal64::error::Error;
+    ///
+    /// fn with_fee(notional: &str, fee: &str) -> Result<DecimalU64<U8>, Error> {
+    ///     let notional = DecimalU64::<U8>::from_str(notional)?;
+    ///     let fee = DecimalU64::<U2>::from_str(fee)?;
+    ///     notional.try_add(fee.rescale()?)
+    /// }
+    ///
+    /// assert_eq!(DecimalU64::from_str("100.75"), with_fee("100.5", "0.25"));
+    /// assert_eq!(Err(Error::Overflow), with_fee("184467440737", "0.1"));
+    /// ```
+    #[inline]
+    pub const fn try_add(self, other: Self) -> Result<Self, Error> {
+        match self.0.checked_add(other.0) {
+            Some(sum) => Ok(Self::new(sum)),
+            None => Err(Error::Overflow),
+        }
+    }
+
+    /// Subtract one decimal from another with the same scale, returning [`Error::Underflow`] if the
+    /// result would be negative.
+    #[inline]
+    pub const fn try_sub(self, other: Self) -> Result<Self, Error> {
+        match self.0.checked_sub(other.0) {
+            Some(diff) => Ok(Self::new(diff)),
+            None => Err(Error::Underflow),
+        }
+    }
+
+    /// Multiply two decimals with the same scale, truncating like `Mul`. Returns [`Error::Overflow`]
+    /// if the result does not fit.
+    #[inline]
+    pub const fn try_mul(self, other: Self) -> Result<Self, Error> {
+        match self.overflowing_mul(other) {
+            (product, false) => Ok(product),
+            (_, true) => Err(Error::Overflow),
+        }
+    }
+
+    /// Divide one decimal by another with the same scale, truncating like `Div`. Returns
+    /// [`Error::DivisionByZero`] if `other` is zero and [`Error::Overflow`] if the result does not fit.
+    #[inline]
+    pub const fn try_div(self, other: Self) -> Result<Self, Error> {
+        if other.0 == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        match self.overflowing_div(other) {
+            (quotient, false) => Ok(quotient),
+            (_, true) => Err(Error::Overflow),
+        }
+    }
+
+    /// Brings both operands to the scale of `S::Output`.
+    #[inline]
+    const fn try_align<B: ScaleMetrics>(lhs: Self, rhs: DecimalU64<B>) -> Result<(u64, u64), Error>
+    where
+        S: MaxScale<B>,
+    {
+        let lhs = match lhs.0.checked_mul(upscale_factor::<S, S::Output>()) {
+            Some(value) => value,
+            None => return Err(Error::Overflow),
+        };
+        let rhs = match rhs.0.checked_mul(upscale_factor::<B, S::Output>()) {
+            Some(value) => value,
+            None => return Err(Error::Overflow),
+        };
+        Ok((lhs, rhs))
+    }
+}
+
+/// Integer division of two decimals with the same scale. Since both operands share the scale, the
+/// quotient is a plain count and the remainder is exact.
+impl<S: ScaleMetrics> DecimalU64<S> {
+    /// Returns the remainder of dividing this decimal by `other`, or `None` if `other` is zero.
+    #[inline]
+    pub const fn checked_rem(self, other: Self) -> Option<Self> {
+        if other.0 == 0 {
+            return None;
+        }
+        Some(Self::new(self.0 % other.0))
+    }
+
+    /// Returns how many whole times `other` fits into this decimal, together with what is left over.
+    /// Returns `None` if `other` is zero.
+    #[inline]
+    pub const fn checked_div_rem(self, other: Self) -> Option<(u64, Self)> {
+        if other.0 == 0 {
+            return None;
+        }
+        Some((self.0 / other.0, Self::new(self.0 % other.0)))
+    }
+
+    /// Returns how many whole times `other` fits into this decimal, together with what is left over.
+    /// Panics if `other` is zero.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U4};
+    ///
+    /// let position = DecimalU64::<U4>::from_str("1.2347").unwrap();
+    /// let lot_size = DecimalU64::<U4>::from_str("0.0005").unwrap();
+    /// let (lots, residual) = position.div_rem(lot_size);
+    /// assert_eq!(2469, lots);
+    /// assert_eq!("0.0002", residual.to_string());
+    /// ```
+    #[inline]
+    pub const fn div_rem(self, other: Self) -> (u64, Self) {
+        match self.checked_div_rem(other) {
+            Some(result) => result,
+            None => panic!("Division by zero"),
+        }
+    }
+
+    /// Returns how many whole times `other` fits into this decimal. Panics if `other` is zero.
+    #[inline]
+    pub const fn div_floor(self, other: Self) -> u64 {
+        if other.0 == 0 {
+            panic!("Division by zero");
+        }
+        self.0 / other.0
+    }
+
+    /// Returns the smallest number of `other` needed to cover this decimal. Panics if `other` is zero.
+    #[inline]
+    pub const fn div_ceil(self, other: Self) -> u64 {
+        if other.0 == 0 {
+            panic!("Division by zero");
+        }
+        self.0.div_ceil(other.0)
+    }
+
+    /// Returns `true` if this decimal is a whole multiple of `other`. Like the integer counterpart, only
+    /// zero is a multiple of zero.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U4};
+    ///
+    /// let lot_size = DecimalU64::<U4>::from_str("0.0005").unwrap();
+    /// assert!(DecimalU64::<U4>::from_str("1.2345").unwrap().is_multiple_of(lot_size));
+    /// assert!(!DecimalU64::<U4>::from_str("1.2347").unwrap().is_multiple_of(lot_size));
+    /// ```
+    #[inline]
+    pub const fn is_multiple_of(self, other: Self) -> bool {
+        if other.0 == 0 {
+            return self.0 == 0;
+        }
+        self.0 % other.0 == 0
+    }
+}
+
+impl<S: ScaleMetrics> DecimalU64<S> {
+    /// Multiply this decimal by an integer count, e.g. a number of contracts. Returns `None` if the
+    /// result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// let price = DecimalU64::<U2>::from_str("101.25").unwrap();
+    /// assert_eq!("303.75", price.checked_mul_int(3).unwrap().to_string());
+    /// assert_eq!("303.75", (price * 3).to_string());
+    /// ```
+    #[inline]
+    pub const fn checked_mul_int(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(value) => Some(Self::new(value)),
+            None => None,
+        }
+    }
+
+    /// Divide this decimal by an integer count, truncating the digits beyond the scale. Returns `None`
+    /// if `rhs` is zero.
+    #[inline]
+    pub const fn checked_div_int(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_div(rhs) {
+            Some(value) => Some(Self::new(value)),
+            None => None,
+        }
+    }
+}
+
+impl<S: ScaleMetrics> DecimalU64<S> {
+    /// Computes `self * b / c` keeping the full 128-bit product, so the result is truncated only once
+    /// and the intermediate cannot overflow. Panics on division by zero or if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// // pro-rata share of a 3 lot fill for an order holding 7 of the 11 resting lots
+    /// let fill = DecimalU64::<U8>::from_str("3").unwrap();
+    /// let resting = DecimalU64::<U8>::from_str("7").unwrap();
+    /// let total = DecimalU64::<U8>::from_str("11").unwrap();
+    /// assert_eq!("1.90909090", fill.mul_div(resting, total).to_string());
+    /// ```
+    #[inline]
+    pub const fn mul_div(self, b: Self, c: Self) -> Self {
+        match self.try_mul_div::<Floor>(b, c) {
+            Ok(value) => value,
+            Err(Error::DivisionByZero) => panic!("Division by zero"),
+            Err(_) => panic!("attempt to divide with overflow"),
+        }
+    }
+
+    /// Computes `self * b / c` keeping the full 128-bit product and resolving the dropped digits once
+    /// with `R`. Returns [`Error::DivisionByZero`] if `c` is zero and [`Error::Overflow`] if the result
+    /// does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::HalfUp;
+    /// use decimal64::{DecimalU64, U4};
+    ///
+    /// // cross EURJPY from EURUSD and USDJPY quoted as JPYUSD
+    /// let eur_usd = DecimalU64::<U4>::from_str("1.0845").unwrap();
+    /// let one = DecimalU64::<U4>::ONE;
+    /// let jpy_usd = DecimalU64::<U4>::from_str("0.0067").unwrap();
+    /// assert_eq!("161.8657", eur_usd.try_mul_div::<HalfUp>(one, jpy_usd).unwrap().to_string());
+    /// ```
+    #[inline]
+    pub const fn try_mul_div<R: RoundingPolicy>(self, b: Self, c: Self) -> Result<Self, Error> {
+        if c.0 == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        // (a / 10^S) * (b / 10^S) / (c / 10^S) scaled back by 10^S leaves the scale factors cancelled out
+        let product = self.0 as u128 * b.0 as u128;
+        let result = div_rounded(product, c.0 as u128, R::MODE);
+        if result > u64::MAX as u128 {
+            return Err(Error::Overflow);
+        }
+        Ok(Self::new(result as u64))
+    }
+}
+
+/// Part of a product or quotient dropped by [`DecimalU64::mul_with_residual`] or
+/// [`DecimalU64::div_with_residual`], held exactly in units of `10^-2S`, i.e. at twice the scale of the
+/// operands.
+///
+/// # Example
+/// ```no_run
+/// use decimal64::{DecimalU64, U2, U4};
+///
+/// let notional = DecimalU64::<U2>::from_str("10.05").unwrap();
+/// let (_, residual) = notional.mul_with_residual(DecimalU64::from_str("0.15").unwrap()).unwrap();
+/// assert_eq!("0.0075", residual.to_string());
+/// assert_eq!("0.0075", residual.to_decimal::<U4>().unwrap().to_string());
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Residual<S>(u64, PhantomData<S>);
+
+impl<S: ScaleMetrics> Residual<S> {
+    #[inline]
+    const fn new(unscaled: u64) -> Self {
+        Self(unscaled, PhantomData)
+    }
+
+    /// Returns the unscaled value in units of `10^-2S`.
+    #[inline]
+    pub const fn unscaled(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the number of fractional digits, which is twice the scale `S`.
+    #[inline]
+    pub const fn scale(&self) -> u8 {
+        2 * S::SCALE
+    }
+
+    /// Returns `true` if nothing was dropped.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Converts the residual into a [`DecimalU64`] of scale `T`, returning [`Error::Inexact`] if any
+    /// non-zero digit would be dropped and [`Error::Overflow`] if the result does not fit.
+    pub const fn to_decimal<T: ScaleMetrics>(&self) -> Result<DecimalU64<T>, Error> {
+        let scale = self.scale();
+        if T::SCALE >= scale {
+            let unscaled = match self.0.checked_mul(SCALE_FACTORS[(T::SCALE - scale) as usize]) {
+                Some(value) => value,
+                None => return Err(Error::Overflow),
+            };
+            Ok(DecimalU64::new(unscaled))
+        } else {
+            let factor = 10u128.pow((scale - T::SCALE) as u32);
+            if self.0 as u128 % factor != 0 {
+                return Err(Error::Inexact);
+            }
+            Ok(DecimalU64::new((self.0 as u128 / factor) as u64))
+        }
+    }
+}
+
+impl<S: ScaleMetrics> Display for Residual<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let scale = self.scale() as usize;
+        if scale == 0 {
+            return write!(f, "{}", self.0);
+        }
+        let factor = 10u128.pow(scale as u32);
+        write!(f, "{}.{:0scale$}", self.0 as u128 / factor, self.0 as u128 % factor)
+    }
+}
+
+/// Arithmetic that never drops digits silently, either failing with [`Error::Inexact`] or handing the
+/// dropped part back to the caller.
+impl<S: ScaleMetrics> DecimalU64<S> {
+    /// Multiply two decimals with the same scale, returning [`Error::Inexact`] if any non-zero digit
+    /// would be dropped and [`Error::Overflow`] if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::error::Error;
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// let price = DecimalU64::<U2>::from_str("10.50").unwrap();
+    /// assert_eq!("21.00", price.mul_exact(DecimalU64::TWO).unwrap().to_string());
+    /// assert_eq!(Err(Error::Inexact), price.mul_exact(DecimalU64::from_str("0.15").unwrap()));
+    /// ```
+    #[inline]
+    pub const fn mul_exact(self, other: Self) -> Result<Self, Error> {
+        match self.mul_with_residual(other) {
+            Ok((product, residual)) if residual.is_zero() => Ok(product),
+            Ok(_) => Err(Error::Inexact),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Divide one decimal by another with the same scale, returning [`Error::Inexact`] if the quotient
+    /// has more digits than the scale, [`Error::DivisionByZero`] if `other` is zero and
+    /// [`Error::Overflow`] if the result does not fit.
+    #[inline]
+    pub const fn div_exact(self, other: Self) -> Result<Self, Error> {
+        match self.div_with_residual(other) {
+            Ok((quotient, residual)) if residual.is_zero() => Ok(quotient),
+            Ok(_) => Err(Error::Inexact),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Multiply two decimals with the same scale, truncating like `Mul` and returning the dropped part of
+    /// the product as a [`Residual`], so that `self * other = product + residual` exactly. Returns
+    /// [`Error::Overflow`] if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// let notional = DecimalU64::<U2>::from_str("10.05").unwrap();
+    /// let fee_rate = DecimalU64::<U2>::from_str("0.15").unwrap();
+    /// let (fee, residual) = notional.mul_with_residual(fee_rate).unwrap();
+    /// assert_eq!("1.50", fee.to_string());
+    /// // 1.5075 - 1.50 = 0.0075
+    /// assert_eq!("0.0075", residual.to_string());
+    /// ```
+    #[inline]
+    pub const fn mul_with_residual(self, other: Self) -> Result<(Self, Residual<S>), Error> {
+        let product = self.0 as u128 * other.0 as u128;
+        let quotient = product / S::SCALE_FACTOR as u128;
+        if quotient > u64::MAX as u128 {
+            return Err(Error::Overflow);
+        }
+        Ok((Self::new(quotient as u64), Residual::new((product % S::SCALE_FACTOR as u128) as u64)))
+    }
+
+    /// Divide one decimal by another with the same scale, truncating like `Div` and returning what is
+    /// left of the dividend as a [`Residual`], so that `self = quotient * other + residual` exactly.
+    /// Returns [`Error::DivisionByZero`] if `other` is zero and [`Error::Overflow`] if the result does
+    /// not fit.
     #[inline]
-    pub fn checked_div(self, other: Self) -> Option<Self> {
+    pub const fn div_with_residual(self, other: Self) -> Result<(Self, Residual<S>), Error> {
         if other.0 == 0 {
-            return None;
+            return Err(Error::DivisionByZero);
         }
-        let dividend = (self.0 as u128).checked_mul(S::SCALE_FACTOR as u128)?;
-        let quotient = dividend / (other.0 as u128);
+        let dividend = self.0 as u128 * S::SCALE_FACTOR as u128;
+        let quotient = dividend / other.0 as u128;
         if quotient > u64::MAX as u128 {
-            None
-        } else {
-            Some(Self::new(quotient as u64))
+            return Err(Error::Overflow);
         }
+        // the remainder is below the divisor and therefore fits into u64
+        Ok((Self::new(quotient as u64), Residual::new((dividend % other.0 as u128) as u64)))
     }
 }
 
 #[cfg(test)]
 mod tests {
     mod mul {
-        use crate::{DecimalU64, U8};
+        use crate::{DecimalU64, U8, U18};
         use rstest_macros::rstest;
 
         #[rstest]
@@ -172,6 +1148,15 @@ mod tests {
             assert_eq!(expected, result.to_string());
         }
 
+        #[test]
+        fn should_mul_at_high_scale() {
+            let a = DecimalU64::<U18>::from_str("1.5").unwrap();
+            let b = DecimalU64::<U18>::from_str("12.000000000000000001").unwrap();
+            assert_eq!("18.000000000000000001", (a * b).to_string());
+            assert_eq!(Some(a * b), a.checked_mul(b));
+            assert!(b.checked_mul(b).is_none());
+        }
+
         #[rstest]
         #[case("1000000000.00000000", "1000000000.00000000")]
         fn should_overflow(#[case] a: &str, #[case] b: &str) {
@@ -237,7 +1222,7 @@ mod tests {
     }
 
     mod div {
-        use crate::{DecimalU64, U8};
+        use crate::{DecimalU64, U8, U18};
         use rstest_macros::rstest;
 
         #[rstest]
@@ -255,6 +1240,19 @@ mod tests {
             assert_eq!(expected, result.to_string());
         }
 
+        #[test]
+        fn should_div_at_high_scale() {
+            let a = DecimalU64::<U18>::from_str("1").unwrap();
+            let b = DecimalU64::<U18>::from_str("3").unwrap();
+            assert_eq!("0.333333333333333333", (a / b).to_string());
+            assert_eq!(Some(a / b), a.checked_div(b));
+            assert!(
+                DecimalU64::<U18>::TEN
+                    .checked_div(DecimalU64::new(5 * 10u64.pow(17)))
+                    .is_none()
+            );
+        }
+
         #[test]
         fn should_not_checked_div_by_zero() {
             let dec_a = DecimalU64::<U8>::from_str("123.45678901").unwrap();
@@ -279,6 +1277,672 @@ mod tests {
         }
     }
 
+    mod rounding {
+        use crate::round::{Ceil, Floor, HalfDown, HalfEven, HalfOdd, HalfUp};
+        use crate::{DecimalU64, U2, U8};
+        use rstest_macros::rstest;
+
+        #[rstest]
+        #[case("10.05", "0.15", "1.50", "1.51", "1.51")]
+        #[case("10.00", "0.15", "1.50", "1.50", "1.50")]
+        #[case("0.05", "0.10", "0.00", "0.01", "0.01")]
+        #[case("0.04", "0.10", "0.00", "0.00", "0.01")]
+        fn should_mul_round(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] floor: &str,
+            #[case] half_up: &str,
+            #[case] ceil: &str,
+        ) {
+            let a = DecimalU64::<U2>::from_str(a).unwrap();
+            let b = DecimalU64::<U2>::from_str(b).unwrap();
+            assert_eq!(floor, a.mul_round::<Floor>(b).to_string());
+            assert_eq!(half_up, a.mul_round::<HalfUp>(b).to_string());
+            assert_eq!(ceil, a.checked_mul_round::<Ceil>(b).unwrap().to_string());
+            assert_eq!(a * b, a.mul_round::<Floor>(b));
+        }
+
+        #[rstest]
+        #[case("10.05", "0.15", "1.51", "1.51", "1.51")]
+        #[case("0.05", "0.10", "0.00", "0.00", "0.01")]
+        #[case("0.15", "0.10", "0.02", "0.01", "0.01")]
+        #[case("0.04", "0.10", "0.00", "0.00", "0.00")]
+        fn should_mul_round_ties(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] half_even: &str,
+            #[case] half_down: &str,
+            #[case] half_odd: &str,
+        ) {
+            let a = DecimalU64::<U2>::from_str(a).unwrap();
+            let b = DecimalU64::<U2>::from_str(b).unwrap();
+            assert_eq!(half_even, a.mul_round::<HalfEven>(b).to_string());
+            assert_eq!(half_down, a.mul_round::<HalfDown>(b).to_string());
+            assert_eq!(half_odd, a.checked_mul_round::<HalfOdd>(b).unwrap().to_string());
+        }
+
+        #[rstest]
+        #[case("2", "3", "0.66", "0.67", "0.67")]
+        #[case("1", "3", "0.33", "0.33", "0.34")]
+        #[case("1", "8", "0.12", "0.13", "0.13")]
+        #[case("1", "4", "0.25", "0.25", "0.25")]
+        fn should_div_round(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] floor: &str,
+            #[case] half_up: &str,
+            #[case] ceil: &str,
+        ) {
+            let a = DecimalU64::<U2>::from_str(a).unwrap();
+            let b = DecimalU64::<U2>::from_str(b).unwrap();
+            assert_eq!(floor, a.div_round::<Floor>(b).to_string());
+            assert_eq!(half_up, a.div_round::<HalfUp>(b).to_string());
+            assert_eq!(ceil, a.checked_div_round::<Ceil>(b).unwrap().to_string());
+            assert_eq!(a / b, a.div_round::<Floor>(b));
+        }
+
+        #[rstest]
+        #[case("2", "3", "0.67", "0.67", "0.67")]
+        #[case("1", "8", "0.12", "0.12", "0.13")]
+        #[case("3", "8", "0.38", "0.37", "0.37")]
+        #[case("1", "4", "0.25", "0.25", "0.25")]
+        fn should_div_round_ties(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] half_even: &str,
+            #[case] half_down: &str,
+            #[case] half_odd: &str,
+        ) {
+            let a = DecimalU64::<U2>::from_str(a).unwrap();
+            let b = DecimalU64::<U2>::from_str(b).unwrap();
+            assert_eq!(half_even, a.div_round::<HalfEven>(b).to_string());
+            assert_eq!(half_down, a.div_round::<HalfDown>(b).to_string());
+            assert_eq!(half_odd, a.checked_div_round::<HalfOdd>(b).unwrap().to_string());
+        }
+
+        #[test]
+        fn should_not_overflow_when_rounding_up() {
+            let max = DecimalU64::<U8>::MAX;
+            assert_eq!(Some(max), max.checked_mul_round::<Ceil>(DecimalU64::ONE));
+            assert!(max.checked_mul_round::<Ceil>(DecimalU64::new(100_000_001)).is_none());
+            assert!(max.checked_div_round::<HalfUp>(DecimalU64::new(99_999_999)).is_none());
+            assert!(max.checked_div_round::<HalfUp>(DecimalU64::ZERO).is_none());
+        }
+
+        #[test]
+        #[should_panic = "Division by zero"]
+        fn should_panic_if_div_round_by_zero() {
+            let _ = DecimalU64::<U8>::ONE.div_round::<HalfUp>(DecimalU64::ZERO);
+        }
+    }
+
+    mod mixed {
+        use crate::round::{Ceil, Floor, HalfUp};
+        use crate::{DecimalU64, U0, U2, U4, U8, U10};
+        use rstest_macros::rstest;
+
+        #[test]
+        fn should_add_and_sub_at_max_scale() {
+            let price = DecimalU64::<U2>::from_str("101.25").unwrap();
+            let offset = DecimalU64::<U8>::from_str("0.00000001").unwrap();
+            let sum: DecimalU64<U8> = price.add_to(offset);
+            assert_eq!("101.25000001", sum.to_string());
+            assert_eq!("101.25000001", offset.add_to(price).to_string());
+            assert_eq!("101.24999999", price.sub_to(offset).to_string());
+            assert_eq!(Some(sum), price.checked_add_to(offset));
+            assert_eq!(Some(sum), offset.checked_add_to(price));
+            assert_eq!("101.24999999", price.checked_sub_to(offset).unwrap().to_string());
+        }
+
+        #[test]
+        fn should_not_overflow_mixed_add_and_sub() {
+            let max = DecimalU64::<U0>::MAX;
+            let one = DecimalU64::<U2>::ONE;
+            assert!(max.checked_add_to(one).is_none());
+            assert!(one.checked_sub_to(max).is_none());
+            assert!(
+                one.checked_sub_to(DecimalU64::<U8>::from_str("1.00000001").unwrap())
+                    .is_none()
+            );
+            assert!(DecimalU64::<U2>::MAX.checked_add_to(DecimalU64::<U8>::ONE).is_none());
+            assert!(DecimalU64::<U2>::MAX.checked_sub_to(DecimalU64::<U8>::ONE).is_none());
+        }
+
+        #[test]
+        #[should_panic = "attempt to add with overflow"]
+        fn should_panic_if_add_to_overflows() {
+            let _ = DecimalU64::<U0>::MAX.add_to(DecimalU64::<U2>::ONE);
+        }
+
+        #[test]
+        #[should_panic = "attempt to subtract with overflow"]
+        fn should_panic_if_sub_to_underflows() {
+            let _ = DecimalU64::<U2>::ONE.sub_to(DecimalU64::<U8>::TWO);
+        }
+
+        #[test]
+        fn should_keep_same_scale_operators_inferable() {
+            let price = DecimalU64::<U8>::from_str("1.5").unwrap();
+            assert_eq!(DecimalU64::<U8>::from_str("2.5").unwrap(), price + DecimalU64::ONE);
+            assert_eq!(DecimalU64::<U8>::from_str("0.5").unwrap(), price - DecimalU64::ONE);
+            assert_eq!(Some(price), price.checked_add(DecimalU64::ZERO));
+            assert_eq!(Some(price), price.checked_sub(DecimalU64::ZERO));
+        }
+
+        #[rstest]
+        #[case("101.25", "0.00012345", "0.0124993125")]
+        #[case("0.01", "0.00000001", "0.0000000001")]
+        fn should_mul_to_exact_scale(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+            let a = DecimalU64::<U2>::from_str(a).unwrap();
+            let b = DecimalU64::<U8>::from_str(b).unwrap();
+            let result = a.checked_mul_to::<U10, HalfUp, _>(b).unwrap();
+            assert_eq!(expected, result.to_string());
+            assert_eq!(result, a.mul_to::<U10, Floor, _>(b));
+        }
+
+        #[rstest]
+        #[case("101.25", "0.00012345", "0.0124", "0.0125", "0.0125")]
+        #[case("2.5", "0.0001", "0.0002", "0.0003", "0.0003")]
+        #[case("2", "0.0001", "0.0002", "0.0002", "0.0002")]
+        fn should_mul_to_with_rounding(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] floor: &str,
+            #[case] half_up: &str,
+            #[case] ceil: &str,
+        ) {
+            let a = DecimalU64::<U2>::from_str(a).unwrap();
+            let b = DecimalU64::<U8>::from_str(b).unwrap();
+            assert_eq!(floor, a.mul_to::<U4, Floor, _>(b).to_string());
+            assert_eq!(half_up, a.mul_to::<U4, HalfUp, _>(b).to_string());
+            assert_eq!(ceil, a.mul_to::<U4, Ceil, _>(b).to_string());
+        }
+
+        #[test]
+        fn should_not_overflow_mul_to() {
+            let max = DecimalU64::<U0>::MAX;
+            assert!(max.checked_mul_to::<U0, HalfUp, _>(DecimalU64::<U2>::TWO).is_none());
+            assert!(max.checked_mul_to::<U2, HalfUp, _>(DecimalU64::<U0>::ONE).is_none());
+            assert_eq!(Some(max), max.checked_mul_to::<U0, HalfUp, _>(DecimalU64::<U8>::ONE));
+        }
+
+        #[rstest]
+        #[case("100", "3", "33.33", "33.33", "33.34")]
+        #[case("2", "3", "0.66", "0.67", "0.67")]
+        #[case("0.01", "0.00000002", "500000.00", "500000.00", "500000.00")]
+        fn should_div_to_with_rounding(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] floor: &str,
+            #[case] half_up: &str,
+            #[case] ceil: &str,
+        ) {
+            let a = DecimalU64::<U4>::from_str(a).unwrap();
+            let b = DecimalU64::<U8>::from_str(b).unwrap();
+            assert_eq!(floor, a.div_to::<U2, Floor, _>(b).to_string());
+            assert_eq!(half_up, a.div_to::<U2, HalfUp, _>(b).to_string());
+            assert_eq!(ceil, a.checked_div_to::<U2, Ceil, _>(b).unwrap().to_string());
+        }
+
+        #[test]
+        fn should_div_to_lower_scale_than_operands() {
+            let a = DecimalU64::<U8>::from_str("12.34567891").unwrap();
+            let b = DecimalU64::<U2>::from_str("0.5").unwrap();
+            assert_eq!("24", a.div_to::<U0, Floor, _>(b).to_string());
+            assert_eq!("25", a.div_to::<U0, HalfUp, _>(b).to_string());
+        }
+
+        #[test]
+        fn should_not_overflow_div_to() {
+            let max = DecimalU64::<U0>::MAX;
+            assert!(max.checked_div_to::<U8, HalfUp, _>(DecimalU64::<U0>::ONE).is_none());
+            assert!(max.checked_div_to::<U0, HalfUp, _>(DecimalU64::<U2>::ZERO).is_none());
+            assert!(max.checked_div_to::<U0, Ceil, _>(DecimalU64::<U2>::new(99)).is_none());
+        }
+
+        #[test]
+        #[should_panic = "Division by zero"]
+        fn should_panic_if_div_to_by_zero() {
+            let _ = DecimalU64::<U2>::ONE.div_to::<U2, HalfUp, _>(DecimalU64::<U8>::ZERO);
+        }
+    }
+
+    mod overflow {
+        use crate::{DecimalU64, U8};
+        use rstest_macros::rstest;
+
+        type D = DecimalU64<U8>;
+
+        #[rstest]
+        #[case("1.5", "2", "3.50000000", false)]
+        #[case("184467440737.09551615", "0", "184467440737.09551615", false)]
+        #[case("184467440737.09551615", "0.00000001", "0.00000000", true)]
+        #[case("184467440737.09551615", "0.00000003", "0.00000002", true)]
+        fn should_overflowing_add(#[case] a: &str, #[case] b: &str, #[case] expected: &str, #[case] overflow: bool) {
+            let (a, b) = (D::from_str(a).unwrap(), D::from_str(b).unwrap());
+            let (result, overflowed) = a.overflowing_add(b);
+            assert_eq!((expected, overflow), (result.to_string().as_str(), overflowed));
+            assert_eq!(result, a.wrapping_add(b));
+            assert_eq!(a.checked_add(b).unwrap_or(D::MAX), a.saturating_add(b));
+        }
+
+        #[rstest]
+        #[case("3.5", "2", "1.50000000", false)]
+        #[case("1", "1", "0.00000000", false)]
+        #[case("0", "0.00000001", "184467440737.09551615", true)]
+        fn should_overflowing_sub(#[case] a: &str, #[case] b: &str, #[case] expected: &str, #[case] overflow: bool) {
+            let (a, b) = (D::from_str(a).unwrap(), D::from_str(b).unwrap());
+            let (result, overflowed) = a.overflowing_sub(b);
+            assert_eq!((expected, overflow), (result.to_string().as_str(), overflowed));
+            assert_eq!(result, a.wrapping_sub(b));
+            assert_eq!(a.checked_sub(b).unwrap_or(D::ZERO), a.saturating_sub(b));
+        }
+
+        #[rstest]
+        #[case("0.2", "50000", "10000.00000000", false)]
+        #[case("184467440737.09551615", "1", "184467440737.09551615", false)]
+        #[case("184467440737.09551615", "1.00000001", "1844.67440736", true)]
+        #[case("1000000000", "1000000000", "159089797835.94147840", true)]
+        fn should_overflowing_mul(#[case] a: &str, #[case] b: &str, #[case] expected: &str, #[case] overflow: bool) {
+            let (a, b) = (D::from_str(a).unwrap(), D::from_str(b).unwrap());
+            let (result, overflowed) = a.overflowing_mul(b);
+            assert_eq!((expected, overflow), (result.to_string().as_str(), overflowed));
+            assert_eq!(result, a.wrapping_mul(b));
+            assert_eq!(a.checked_mul(b).unwrap_or(D::MAX), a.saturating_mul(b));
+        }
+
+        #[rstest]
+        #[case("1", "4", "0.25000000", false)]
+        #[case("184467440737.09551615", "1", "184467440737.09551615", false)]
+        #[case("184467440737.09551615", "0.99999999", "1844.67442580", true)]
+        #[case("1000000", "0.00000001", "18647120494.23024128", true)]
+        fn should_overflowing_div(#[case] a: &str, #[case] b: &str, #[case] expected: &str, #[case] overflow: bool) {
+            let (a, b) = (D::from_str(a).unwrap(), D::from_str(b).unwrap());
+            let (result, overflowed) = a.overflowing_div(b);
+            assert_eq!((expected, overflow), (result.to_string().as_str(), overflowed));
+            assert_eq!(result, a.wrapping_div(b));
+            assert_eq!(a.checked_div(b).unwrap_or(D::MAX), a.saturating_div(b));
+        }
+
+        #[test]
+        fn should_saturate() {
+            assert_eq!(D::MAX, D::MAX.saturating_add(D::ONE));
+            assert_eq!(D::ZERO, D::ONE.saturating_sub(D::TWO));
+            assert_eq!(D::MAX, D::MAX.saturating_mul(D::TWO));
+            assert_eq!(D::MAX, D::MAX.saturating_div(D::from_str("0.5").unwrap()));
+        }
+
+        #[test]
+        #[should_panic = "Division by zero"]
+        fn should_panic_if_saturating_div_by_zero() {
+            let _ = D::ONE.saturating_div(D::ZERO);
+        }
+
+        #[test]
+        #[should_panic = "Division by zero"]
+        fn should_panic_if_wrapping_div_by_zero() {
+            let _ = D::ONE.wrapping_div(D::ZERO);
+        }
+    }
+
+    mod try_ops {
+        use crate::error::Error;
+        use crate::{DecimalU64, U8};
+
+        type D = DecimalU64<U8>;
+
+        #[test]
+        fn should_try_add() {
+            assert_eq!(Ok(D::THREE), D::ONE.try_add(D::TWO));
+            assert_eq!(Err(Error::Overflow), D::MAX.try_add(D::new(1)));
+        }
+
+        #[test]
+        fn should_try_sub() {
+            assert_eq!(Ok(D::ONE), D::THREE.try_sub(D::TWO));
+            assert_eq!(Ok(D::ZERO), D::ONE.try_sub(D::ONE));
+            assert_eq!(Err(Error::Underflow), D::ONE.try_sub(D::TWO));
+        }
+
+        #[test]
+        fn should_try_mul() {
+            assert_eq!(Ok(D::SIX), D::TWO.try_mul(D::THREE));
+            assert_eq!(Err(Error::Overflow), D::MAX.try_mul(D::TWO));
+        }
+
+        #[test]
+        fn should_try_div() {
+            assert_eq!(Ok(D::THREE), D::SIX.try_div(D::TWO));
+            assert_eq!(Err(Error::DivisionByZero), D::ONE.try_div(D::ZERO));
+            assert_eq!(Err(Error::Overflow), D::MAX.try_div(D::from_str("0.5").unwrap()));
+        }
+
+        #[test]
+        fn should_chain_with_question_mark() -> Result<(), Error> {
+            let price = D::from_str("101.25")?;
+            let quantity = D::from_str("2")?;
+            let notional = price.try_mul(quantity)?.try_sub(D::from_str("0.5")?)?;
+            assert_eq!("202.00000000", notional.to_string());
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "strict")]
+    mod strict {
+        use crate::{DecimalU64, U8};
+
+        type D = DecimalU64<U8>;
+
+        #[test]
+        #[should_panic = "attempt to add with overflow"]
+        fn should_panic_on_add_overflow() {
+            let _ = D::MAX + D::new(1);
+        }
+
+        #[test]
+        #[should_panic = "attempt to subtract with overflow"]
+        fn should_panic_on_sub_overflow() {
+            let _ = D::ONE - D::TWO;
+        }
+
+        #[test]
+        #[should_panic = "attempt to multiply with overflow"]
+        fn should_panic_on_mul_overflow() {
+            let _ = D::MAX * D::TWO;
+        }
+
+        #[test]
+        #[should_panic = "attempt to divide with overflow"]
+        fn should_panic_on_div_overflow() {
+            let _ = D::MAX / D::from_str("0.5").unwrap();
+        }
+
+        #[test]
+        #[should_panic = "attempt to add with overflow"]
+        fn should_panic_on_add_assign_overflow() {
+            let mut value = D::MAX;
+            value += D::new(1);
+        }
+
+        #[test]
+        #[should_panic = "attempt to subtract with overflow"]
+        fn should_panic_on_sub_assign_overflow() {
+            let mut value = D::ZERO;
+            value -= D::new(1);
+        }
+
+        #[test]
+        #[should_panic = "attempt to add with overflow"]
+        fn should_panic_on_sum_overflow() {
+            let _: D = [D::MAX, D::ONE].into_iter().sum();
+        }
+    }
+
+    mod rem {
+        use crate::{DecimalU64, U4};
+        use rstest_macros::rstest;
+
+        type D = DecimalU64<U4>;
+
+        #[rstest]
+        #[case("1.2347", "0.0005", 2469, "0.0002")]
+        #[case("1.2345", "0.0005", 2469, "0.0000")]
+        #[case("0.0003", "0.0005", 0, "0.0003")]
+        #[case("10", "3", 3, "1.0000")]
+        #[case("0", "0.0001", 0, "0.0000")]
+        fn should_div_rem(#[case] a: &str, #[case] b: &str, #[case] quotient: u64, #[case] remainder: &str) {
+            let (a, b) = (D::from_str(a).unwrap(), D::from_str(b).unwrap());
+            let (q, r) = a.div_rem(b);
+            assert_eq!((quotient, remainder), (q, r.to_string().as_str()));
+            assert_eq!(Some(a.div_rem(b)), a.checked_div_rem(b));
+            assert_eq!(remainder, (a % b).to_string());
+            assert_eq!(Some(a % b), a.checked_rem(b));
+            assert_eq!(quotient, a.div_floor(b));
+            assert_eq!(remainder == "0.0000", a.is_multiple_of(b));
+            let ceil = if remainder == "0.0000" { quotient } else { quotient + 1 };
+            assert_eq!(ceil, a.div_ceil(b));
+
+            let mut value = a;
+            value %= b;
+            assert_eq!(a % b, value);
+        }
+
+        #[test]
+        fn should_handle_zero_divisor() {
+            assert!(D::ONE.checked_rem(D::ZERO).is_none());
+            assert!(D::ONE.checked_div_rem(D::ZERO).is_none());
+            assert!(D::ZERO.is_multiple_of(D::ZERO));
+            assert!(!D::ONE.is_multiple_of(D::ZERO));
+        }
+
+        #[test]
+        fn should_div_at_max() {
+            assert_eq!((1, D::ZERO), D::MAX.div_rem(D::MAX));
+            assert_eq!(u64::MAX, D::MAX.div_floor(D::new(1)));
+            assert_eq!(2, D::MAX.div_ceil(D::new(u64::MAX - 1)));
+        }
+
+        #[test]
+        #[should_panic = "Division by zero"]
+        fn should_panic_if_rem_by_zero() {
+            let _ = D::ONE % D::ZERO;
+        }
+
+        #[test]
+        #[should_panic = "Division by zero"]
+        fn should_panic_if_div_ceil_by_zero() {
+            let _ = D::ONE.div_ceil(D::ZERO);
+        }
+    }
+
+    mod scalar {
+        use crate::{DecimalU64, U2, U18};
+        use rstest_macros::rstest;
+
+        type D = DecimalU64<U2>;
+
+        #[rstest]
+        #[case("101.25", 3, "303.75")]
+        #[case("0.01", 0, "0.00")]
+        #[case("0", 1000, "0.00")]
+        fn should_mul_int(#[case] a: &str, #[case] b: u64, #[case] expected: &str) {
+            let a = D::from_str(a).unwrap();
+            assert_eq!(expected, (a * b).to_string());
+            assert_eq!(expected, (b * a).to_string());
+            assert_eq!(expected, a.checked_mul_int(b).unwrap().to_string());
+            let mut value = a;
+            value *= b;
+            assert_eq!(expected, value.to_string());
+        }
+
+        #[rstest]
+        #[case("303.75", 3, "101.25")]
+        #[case("1.00", 3, "0.33")]
+        #[case("0.01", 2, "0.00")]
+        fn should_div_int(#[case] a: &str, #[case] b: u64, #[case] expected: &str) {
+            let a = D::from_str(a).unwrap();
+            assert_eq!(expected, (a / b).to_string());
+            assert_eq!(expected, a.checked_div_int(b).unwrap().to_string());
+            let mut value = a;
+            value /= b;
+            assert_eq!(expected, value.to_string());
+        }
+
+        #[test]
+        fn should_not_overflow_scaled_representation() {
+            // 100 cannot be represented at U18, but is still a valid multiplier
+            assert!(DecimalU64::<U18>::from_str("100").is_err());
+            let value = DecimalU64::<U18>::from_str("0.15").unwrap();
+            assert_eq!("15.000000000000000000", (value * 100u64).to_string());
+            assert!(DecimalU64::<U18>::MAX.checked_mul_int(2).is_none());
+            assert!(D::ONE.checked_div_int(0).is_none());
+        }
+
+        #[test]
+        fn should_accept_unsuffixed_literals() {
+            let price = D::from_str("101.25").unwrap();
+            assert_eq!("303.75", (price * 3).to_string());
+            assert_eq!("303.75", (3 * price).to_string());
+            assert_eq!("33.75", (price / 3).to_string());
+            let mut value = price;
+            value *= 2;
+            value /= 4;
+            assert_eq!("50.62", value.to_string());
+        }
+
+        #[test]
+        #[should_panic = "Division by zero"]
+        fn should_panic_if_div_int_by_zero() {
+            let _ = D::ONE / 0u64;
+        }
+    }
+
+    mod mul_div {
+        use crate::error::Error;
+        use crate::round::{Ceil, Floor, HalfUp};
+        use crate::{DecimalU64, U2, U8};
+        use rstest_macros::rstest;
+
+        type D = DecimalU64<U8>;
+
+        #[rstest]
+        #[case("3", "7", "11", "1.90909090", "1.90909091", "1.90909091")]
+        #[case("2", "1", "3", "0.66666666", "0.66666667", "0.66666667")]
+        #[case("1", "1", "8", "0.12500000", "0.12500000", "0.12500000")]
+        #[case("0.00000001", "0.5", "1", "0.00000000", "0.00000001", "0.00000001")]
+        fn should_mul_div(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] c: &str,
+            #[case] floor: &str,
+            #[case] half_up: &str,
+            #[case] ceil: &str,
+        ) {
+            let (a, b, c) = (D::from_str(a).unwrap(), D::from_str(b).unwrap(), D::from_str(c).unwrap());
+            assert_eq!(floor, a.mul_div(b, c).to_string());
+            assert_eq!(floor, a.try_mul_div::<Floor>(b, c).unwrap().to_string());
+            assert_eq!(half_up, a.try_mul_div::<HalfUp>(b, c).unwrap().to_string());
+            assert_eq!(ceil, a.try_mul_div::<Ceil>(b, c).unwrap().to_string());
+        }
+
+        #[test]
+        fn should_not_overflow_intermediate() {
+            // the product alone does not fit, but the final result does
+            let notional = D::from_str("100000000000").unwrap();
+            let price = D::from_str("50000").unwrap();
+            assert!(notional.checked_mul(price).is_none());
+            assert_eq!("100000000000.00000000", notional.mul_div(price, price).to_string());
+        }
+
+        #[test]
+        fn should_round_once() {
+            // mul then div truncates twice: 0.01 * 0.50 = 0.00, 0.00 / 0.03 = 0.00
+            let (a, b, c) = (DecimalU64::<U2>::new(1), DecimalU64::<U2>::new(50), DecimalU64::<U2>::new(3));
+            assert_eq!(DecimalU64::<U2>::ZERO, a * b / c);
+            assert_eq!("0.17", a.try_mul_div::<HalfUp>(b, c).unwrap().to_string());
+        }
+
+        #[test]
+        fn should_return_error() {
+            assert_eq!(Err(Error::DivisionByZero), D::ONE.try_mul_div::<HalfUp>(D::ONE, D::ZERO));
+            assert_eq!(Err(Error::Overflow), D::MAX.try_mul_div::<HalfUp>(D::TWO, D::ONE));
+            assert_eq!(Ok(D::MAX), D::MAX.try_mul_div::<Ceil>(D::MAX, D::MAX));
+        }
+
+        #[test]
+        #[should_panic = "Division by zero"]
+        fn should_panic_if_div_by_zero() {
+            let _ = D::ONE.mul_div(D::ONE, D::ZERO);
+        }
+
+        #[test]
+        #[should_panic = "attempt to divide with overflow"]
+        fn should_panic_on_overflow() {
+            let _ = D::MAX.mul_div(D::TWO, D::ONE);
+        }
+    }
+
+    mod exact {
+        use crate::arithmetic::Residual;
+        use crate::error::Error;
+        use crate::{DecimalU64, U0, U1, U2, U8, U18};
+        use rstest_macros::rstest;
+
+        type D = DecimalU64<U2>;
+
+        #[rstest]
+        #[case("10.50", "2", Ok("21.00"), 0)]
+        #[case("10.05", "0.15", Err(Error::Inexact), 75)]
+        #[case("0.01", "0.01", Err(Error::Inexact), 1)]
+        #[case("0.10", "0.10", Ok("0.01"), 0)]
+        fn should_mul_exact(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] expected: Result<&str, Error>,
+            #[case] residual: u64,
+        ) {
+            let (a, b) = (D::from_str(a).unwrap(), D::from_str(b).unwrap());
+            assert_eq!(expected.map(|value| D::from_str(value).unwrap()), a.mul_exact(b));
+            let (product, rest) = a.mul_with_residual(b).unwrap();
+            assert_eq!((a * b, residual), (product, rest.unscaled()));
+        }
+
+        #[rstest]
+        #[case("1", "4", Ok("0.25"), 0)]
+        #[case("1", "3", Err(Error::Inexact), 100)]
+        #[case("2", "0.03", Err(Error::Inexact), 2)]
+        fn should_div_exact(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] expected: Result<&str, Error>,
+            #[case] residual: u64,
+        ) {
+            let (a, b) = (D::from_str(a).unwrap(), D::from_str(b).unwrap());
+            assert_eq!(expected.map(|value| D::from_str(value).unwrap()), a.div_exact(b));
+            let (quotient, remainder) = a.div_with_residual(b).unwrap();
+            assert_eq!((a / b, residual), (quotient, remainder.unscaled()));
+            // self = quotient * other + residual, all in units of 10^-4
+            assert_eq!(a.0 as u128 * 100, quotient.0 as u128 * b.0 as u128 + remainder.unscaled() as u128);
+        }
+
+        #[test]
+        fn should_report_errors() {
+            assert_eq!(Err(Error::Overflow), D::MAX.mul_exact(D::TWO));
+            assert_eq!(Err(Error::Overflow), D::MAX.mul_with_residual(D::TWO));
+            assert_eq!(Err(Error::DivisionByZero), D::ONE.div_exact(D::ZERO));
+            assert_eq!(Err(Error::Overflow), D::MAX.div_with_residual(D::from_str("0.5").unwrap()));
+        }
+
+        #[rstest]
+        #[case("10.05", "0.15", "0.0075", Ok("0.00750000"), Err(Error::Inexact))]
+        #[case("0.10", "0.10", "0.0000", Ok("0.00000000"), Ok("0.00"))]
+        #[case("0.01", "0.01", "0.0001", Ok("0.00010000"), Err(Error::Inexact))]
+        fn should_convert_residual(
+            #[case] a: &str,
+            #[case] b: &str,
+            #[case] display: &str,
+            #[case] at_u8: Result<&str, Error>,
+            #[case] at_u2: Result<&str, Error>,
+        ) {
+            let (a, b) = (D::from_str(a).unwrap(), D::from_str(b).unwrap());
+            let (_, residual) = a.mul_with_residual(b).unwrap();
+            assert_eq!(4, residual.scale());
+            assert_eq!(display, residual.to_string());
+            assert_eq!(at_u8.map(|value| DecimalU64::<U8>::from_str(value).unwrap()), residual.to_decimal::<U8>());
+            assert_eq!(at_u2.map(|value| D::from_str(value).unwrap()), residual.to_decimal::<U2>());
+        }
+
+        #[test]
+        fn should_display_residual_at_any_scale() {
+            let (_, residual) = DecimalU64::<U0>::new(7).div_with_residual(DecimalU64::new(2)).unwrap();
+            assert_eq!("1", residual.to_string());
+            let (_, residual) = DecimalU64::<U18>::new(1).mul_with_residual(DecimalU64::new(1)).unwrap();
+            assert_eq!(format!("0.{}1", "0".repeat(35)), residual.to_string());
+            assert_eq!(Err(Error::Inexact), residual.to_decimal::<U18>());
+            assert_eq!(Err(Error::Overflow), Residual::<U0>::new(u64::MAX).to_decimal::<U1>());
+        }
+    }
+
     mod assign {
         use crate::{DecimalU64, U8};
 
@@ -291,6 +1955,51 @@ mod tests {
             one -= two;
             assert_eq!("100.00000000", one.to_string());
         }
+
+        #[test]
+        fn should_assign_by_reference() {
+            let two = DecimalU64::<U8>::TWO;
+            let mut value = DecimalU64::<U8>::from_str("100").unwrap();
+            value += &two;
+            value -= &two;
+            value *= &two;
+            assert_eq!("200.00000000", value.to_string());
+            value /= &two;
+            value *= two;
+            value /= two;
+            assert_eq!("100.00000000", value.to_string());
+            value %= &DecimalU64::<U8>::from_str("30").unwrap();
+            assert_eq!("10.00000000", value.to_string());
+
+            let mut target = &mut value;
+            target -= two;
+            target *= &two;
+            target /= DecimalU64::<U8>::from_str("4").unwrap();
+            assert_eq!("4.00000000", value.to_string());
+        }
+    }
+
+    // the borrowed operands are the point of these tests
+    #[allow(clippy::op_ref)]
+    mod refs {
+        use crate::{DecimalU64, U8};
+
+        #[test]
+        fn should_apply_operators_to_references() {
+            let a = DecimalU64::<U8>::SIX;
+            let b = DecimalU64::<U8>::FOUR;
+            for (expected, result) in [
+                ("10.00000000", [a + b, &a + b, a + &b, &a + &b]),
+                ("2.00000000", [a - b, &a - b, a - &b, &a - &b]),
+                ("24.00000000", [a * b, &a * b, a * &b, &a * &b]),
+                ("1.50000000", [a / b, &a / b, a / &b, &a / &b]),
+                ("2.00000000", [a % b, &a % b, a % &b, &a % &b]),
+            ] {
+                for value in result {
+                    assert_eq!(expected, value.to_string());
+                }
+            }
+        }
     }
 
     mod sum {
@@ -300,11 +2009,33 @@ mod tests {
         fn should_sum_values() {
             let values: Vec<DecimalU64<U8>> = vec![];
             let sum = values.iter().sum::<DecimalU64<U8>>();
-            assert_eq!(sum, DecimalU64::ZERO);
+            assert_eq!(sum, DecimalU64::<U8>::ZERO);
 
             let values: Vec<DecimalU64<U8>> = vec![DecimalU64::ONE, DecimalU64::TWO];
             let sum = values.iter().sum::<DecimalU64<U8>>();
-            assert_eq!(sum, DecimalU64::THREE);
+            assert_eq!(sum, DecimalU64::<U8>::THREE);
+        }
+
+        #[test]
+        fn should_sum_mutable_references() {
+            let mut values = [DecimalU64::<U8>::ONE, DecimalU64::TWO];
+            let sum = values.iter_mut().sum::<DecimalU64<U8>>();
+            assert_eq!(sum, DecimalU64::<U8>::THREE);
+        }
+
+        #[test]
+        fn should_multiply_values() {
+            let values: [DecimalU64<U8>; 0] = [];
+            assert_eq!(DecimalU64::<U8>::ONE, values.into_iter().product());
+
+            let mut values = [
+                DecimalU64::<U8>::TWO,
+                DecimalU64::THREE,
+                DecimalU64::from_str("0.5").unwrap(),
+            ];
+            assert_eq!(DecimalU64::<U8>::THREE, values.iter().product());
+            assert_eq!(DecimalU64::<U8>::THREE, values.iter_mut().product());
+            assert_eq!(DecimalU64::<U8>::THREE, values.into_iter().product());
         }
     }
 }
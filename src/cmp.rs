@@ -0,0 +1,99 @@
+use crate::{DecimalU64, SCALE_FACTORS, ScaleMetrics};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Brings both unscaled values to the larger of the two scales. Scales differ by at most 19 digits,
+/// so the widened values always fit into u128 and the comparison is exact.
+#[inline]
+const fn align<A: ScaleMetrics, B: ScaleMetrics>(lhs: u64, rhs: u64) -> (u128, u128) {
+    if A::SCALE >= B::SCALE {
+        let factor = SCALE_FACTORS[(A::SCALE - B::SCALE) as usize] as u128;
+        (lhs as u128, rhs as u128 * factor)
+    } else {
+        let factor = SCALE_FACTORS[(B::SCALE - A::SCALE) as usize] as u128;
+        (lhs as u128 * factor, rhs as u128)
+    }
+}
+
+impl<A: ScaleMetrics, B: ScaleMetrics> PartialEq<DecimalU64<B>> for DecimalU64<A> {
+    #[inline]
+    fn eq(&self, other: &DecimalU64<B>) -> bool {
+        if A::SCALE == B::SCALE {
+            return self.0 == other.0;
+        }
+        let (lhs, rhs) = align::<A, B>(self.0, other.0);
+        lhs == rhs
+    }
+}
+
+impl<S: ScaleMetrics> Eq for DecimalU64<S> {}
+
+impl<A: ScaleMetrics, B: ScaleMetrics> PartialOrd<DecimalU64<B>> for DecimalU64<A> {
+    #[inline]
+    fn partial_cmp(&self, other: &DecimalU64<B>) -> Option<Ordering> {
+        if A::SCALE == B::SCALE {
+            return Some(self.0.cmp(&other.0));
+        }
+        let (lhs, rhs) = align::<A, B>(self.0, other.0);
+        Some(lhs.cmp(&rhs))
+    }
+}
+
+impl<S: ScaleMetrics> Ord for DecimalU64<S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<S: ScaleMetrics> Hash for DecimalU64<S> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DecimalU64, U0, U1, U2, U8, U18};
+    use rstest_macros::rstest;
+    use std::cmp::Ordering;
+
+    #[rstest]
+    #[case("1.5", "1.50000000", Ordering::Equal)]
+    #[case("1.5", "1.50000001", Ordering::Less)]
+    #[case("1.5", "1.49999999", Ordering::Greater)]
+    #[case("0", "0", Ordering::Equal)]
+    #[case("184467440737.0", "184467440737.09551615", Ordering::Less)]
+    fn should_compare_across_scales(#[case] a: &str, #[case] b: &str, #[case] expected: Ordering) {
+        let a = DecimalU64::<U1>::from_str(a).unwrap();
+        let b = DecimalU64::<U8>::from_str(b).unwrap();
+        assert_eq!(Some(expected), a.partial_cmp(&b));
+        assert_eq!(Some(expected.reverse()), b.partial_cmp(&a));
+        assert_eq!(expected == Ordering::Equal, a == b);
+        assert_eq!(expected == Ordering::Equal, b == a);
+    }
+
+    #[test]
+    fn should_compare_near_max_without_overflow() {
+        let max_u0 = DecimalU64::<U0>::MAX;
+        let max_u18 = DecimalU64::<U18>::MAX;
+        assert!(max_u0 > max_u18);
+        assert!(max_u18 < max_u0);
+        assert_ne!(max_u0, max_u18);
+
+        let eighteen = DecimalU64::<U0>::from_str("18").unwrap();
+        assert!(eighteen < max_u18);
+        assert_eq!(DecimalU64::<U18>::from_str("18").unwrap(), eighteen);
+        assert!(DecimalU64::<U0>::from_str("19").unwrap() > max_u18);
+    }
+
+    #[test]
+    fn should_compare_limits_against_prices() {
+        let limit = DecimalU64::<U2>::from_str("100.25").unwrap();
+        let price = DecimalU64::<U8>::from_str("100.25000001").unwrap();
+        assert!(price > limit);
+        assert!(limit <= price);
+        assert!(limit < price);
+    }
+}
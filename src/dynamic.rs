@@ -0,0 +1,557 @@
+use crate::error::{Error, InvalidInputKind};
+use crate::round::{RoundingMode, RoundingPolicy, div_rounded, round_up};
+use crate::wide::{wide_div, widening_mul};
+use crate::{DecimalU64, MAX_SCALE, ScaleMetrics};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// Fixed point decimal that carries its scale at runtime rather than in the type. Useful when the
+/// precision of an instrument is only known once its metadata has been loaded.
+///
+/// Equality, ordering and hashing are numeric, so `1.5` and `1.50` compare equal.
+#[derive(Debug, Copy, Clone)]
+pub struct DynDecimal {
+    unscaled: u64,
+    scale: u8,
+}
+
+impl Display for DynDecimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut buf = [0u8; Self::REQUIRED_BUFFER_LEN];
+        let len = self.write_to(&mut buf);
+        // Since we know our data is all ASCII, this is safe.
+        let s = unsafe { std::str::from_utf8_unchecked(&buf[..len]) };
+        f.write_str(s)
+    }
+}
+
+impl Default for DynDecimal {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[inline]
+const fn pow10(exp: u8) -> u128 {
+    10u128.pow(exp as u32)
+}
+
+impl DynDecimal {
+    /// Minimum buffer length required by [`Self::write_to`] for any value and scale.
+    pub const REQUIRED_BUFFER_LEN: usize = 21;
+
+    pub const ZERO: Self = Self { unscaled: 0, scale: 0 };
+
+    /// Creates a decimal from its unscaled value and scale, returning
+    /// [`InvalidInputKind::ScaleTooLarge`] if the scale exceeds [`MAX_SCALE`].
+    #[inline]
+    pub const fn new(unscaled: u64, scale: u8) -> Result<Self, Error> {
+        if scale > MAX_SCALE {
+            return Err(Error::InvalidInput(InvalidInputKind::ScaleTooLarge));
+        }
+        Ok(Self { unscaled, scale })
+    }
+
+    /// Returns the unscaled value.
+    #[inline]
+    pub const fn unscaled(&self) -> u64 {
+        self.unscaled
+    }
+
+    /// Returns the number of fractional digits.
+    #[inline]
+    pub const fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Parses a decimal from an ASCII byte slice, inferring the scale from the number of fractional
+    /// digits present in the input. More than [`MAX_SCALE`] fractional digits are rejected with
+    /// [`InvalidInputKind::TooManyFractionalDigits`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::DynDecimal;
+    ///
+    /// let value = DynDecimal::from_slice(b"12.340").unwrap();
+    /// assert_eq!(3, value.scale());
+    /// assert_eq!("12.340", value.to_string());
+    /// ```
+    pub const fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let mut unscaled: u64 = 0;
+        let mut fractional_part_flag: u8 = 0;
+        let mut scale_counter: u8 = 0;
+        let mut index: usize = 0;
+
+        while index < bytes.len() {
+            let byte = bytes[index];
+            match byte {
+                b'0'..=b'9' => {
+                    let next = match unscaled.checked_mul(10) {
+                        Some(value) => value,
+                        None => return Err(Error::Overflow),
+                    };
+                    let digit = (byte - b'0') as u64;
+                    unscaled = match next.checked_add(digit) {
+                        Some(value) => value,
+                        None => return Err(Error::Overflow),
+                    };
+
+                    if scale_counter == MAX_SCALE && fractional_part_flag == 1 {
+                        return Err(Error::InvalidInput(InvalidInputKind::TooManyFractionalDigits));
+                    }
+                    scale_counter += fractional_part_flag;
+                }
+                b'.' => fractional_part_flag = 1,
+                other => return Err(Error::InvalidInput(InvalidInputKind::InvalidCharacter(other as char))),
+            }
+
+            index += 1;
+        }
+
+        Self::new(unscaled, scale_counter)
+    }
+
+    /// Parses a decimal from a UTF-8 string slice, inferring the scale.
+    pub const fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_slice(s.as_bytes())
+    }
+
+    /// Converts this decimal to `f64`.
+    pub const fn to_f64(self) -> f64 {
+        self.unscaled as f64 / pow10(self.scale) as f64
+    }
+
+    /// Converts a value held at scale `from` into scale `to`, resolving dropped digits by `mode`.
+    const fn convert(value: u128, from: u8, to: u8, mode: RoundingMode) -> Result<u64, Error> {
+        let result = if to >= from {
+            match value.checked_mul(pow10(to - from)) {
+                Some(value) => value,
+                None => return Err(Error::Overflow),
+            }
+        } else {
+            div_rounded(value, pow10(from - to), mode)
+        };
+        if result > u64::MAX as u128 {
+            return Err(Error::Overflow);
+        }
+        Ok(result as u64)
+    }
+
+    /// Rescales this decimal to `scale`, returning an error on overflow.
+    /// Downscaling rounds half-up when fractional digits are dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::DynDecimal;
+    ///
+    /// let value = DynDecimal::from_str("1.2050").unwrap();
+    /// assert_eq!("1.21", value.rescale(2).unwrap().to_string());
+    /// ```
+    pub const fn rescale(&self, scale: u8) -> Result<Self, Error> {
+        if scale > MAX_SCALE {
+            return Err(Error::InvalidInput(InvalidInputKind::ScaleTooLarge));
+        }
+        match Self::convert(self.unscaled as u128, self.scale, scale, RoundingMode::HalfUp) {
+            Ok(unscaled) => Ok(Self { unscaled, scale }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Converts this decimal into a [`DecimalU64`] of scale `S`, rounding half-up if `S` has fewer
+    /// fractional digits and returning an error on overflow.
+    pub const fn to_decimal<S: ScaleMetrics>(&self) -> Result<DecimalU64<S>, Error> {
+        match Self::convert(self.unscaled as u128, self.scale, S::SCALE, RoundingMode::HalfUp) {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Creates a decimal carrying the scale of `S`.
+    pub const fn from_decimal<S: ScaleMetrics>(value: DecimalU64<S>) -> Self {
+        Self {
+            unscaled: value.0,
+            scale: S::SCALE,
+        }
+    }
+
+    /// Brings both operands to their common (larger) scale.
+    #[inline]
+    const fn align(self, other: Self) -> (u128, u128, u8) {
+        if self.scale >= other.scale {
+            let factor = pow10(self.scale - other.scale);
+            (self.unscaled as u128, other.unscaled as u128 * factor, self.scale)
+        } else {
+            let factor = pow10(other.scale - self.scale);
+            (self.unscaled as u128 * factor, other.unscaled as u128, other.scale)
+        }
+    }
+
+    /// Adds two decimals of any scale, returning the sum at `scale` with the dropped digits resolved
+    /// by `R`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::DynDecimal;
+    /// use decimal64::round::{Floor, HalfUp};
+    ///
+    /// let price = DynDecimal::from_str("1.25").unwrap();
+    /// let tick = DynDecimal::from_str("0.005").unwrap();
+    /// assert_eq!("1.255", price.add_to::<HalfUp>(tick, 3).unwrap().to_string());
+    /// assert_eq!("1.26", price.add_to::<HalfUp>(tick, 2).unwrap().to_string());
+    /// assert_eq!("1.25", price.add_to::<Floor>(tick, 2).unwrap().to_string());
+    /// ```
+    pub const fn add_to<R: RoundingPolicy>(self, other: Self, scale: u8) -> Result<Self, Error> {
+        if scale > MAX_SCALE {
+            return Err(Error::InvalidInput(InvalidInputKind::ScaleTooLarge));
+        }
+        // only one side is scaled up, by at most 10^19, so the sum stays below 2^64 * 10^19 + 2^64 < u128::MAX
+        let (lhs, rhs, common) = self.align(other);
+        match Self::convert(lhs + rhs, common, scale, R::MODE) {
+            Ok(unscaled) => Ok(Self { unscaled, scale }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Subtracts `other` from this decimal, returning the difference at `scale` with the dropped digits
+    /// resolved by `R`. Returns [`Error::Underflow`] if the result would be negative.
+    pub const fn sub_to<R: RoundingPolicy>(self, other: Self, scale: u8) -> Result<Self, Error> {
+        if scale > MAX_SCALE {
+            return Err(Error::InvalidInput(InvalidInputKind::ScaleTooLarge));
+        }
+        let (lhs, rhs, common) = self.align(other);
+        if rhs > lhs {
+            return Err(Error::Underflow);
+        }
+        match Self::convert(lhs - rhs, common, scale, R::MODE) {
+            Ok(unscaled) => Ok(Self { unscaled, scale }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Multiplies two decimals of any scale, returning the product at `scale` with the dropped digits
+    /// resolved by `R`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::DynDecimal;
+    /// use decimal64::round::{Ceil, HalfUp};
+    ///
+    /// let price = DynDecimal::from_str("101.25").unwrap();
+    /// let quantity = DynDecimal::from_str("0.00012345").unwrap();
+    /// assert_eq!("0.0124993", price.mul_to::<HalfUp>(quantity, 7).unwrap().to_string());
+    /// assert_eq!("0.0124994", price.mul_to::<Ceil>(quantity, 7).unwrap().to_string());
+    /// ```
+    pub const fn mul_to<R: RoundingPolicy>(self, other: Self, scale: u8) -> Result<Self, Error> {
+        if scale > MAX_SCALE {
+            return Err(Error::InvalidInput(InvalidInputKind::ScaleTooLarge));
+        }
+        // the exact product has at most 38 fractional digits and always fits into u128
+        let product = self.unscaled as u128 * other.unscaled as u128;
+        let product_scale = self.scale + other.scale;
+        let result = if scale >= product_scale {
+            match product.checked_mul(pow10(scale - product_scale)) {
+                Some(value) => value,
+                None => return Err(Error::Overflow),
+            }
+        } else {
+            div_rounded(product, pow10(product_scale - scale), R::MODE)
+        };
+        if result > u64::MAX as u128 {
+            return Err(Error::Overflow);
+        }
+        Ok(Self {
+            unscaled: result as u64,
+            scale,
+        })
+    }
+
+    /// Divides this decimal by `other`, returning the quotient at `scale` with the dropped digits
+    /// resolved by `R`. Returns [`Error::DivisionByZero`] if `other` is zero.
+    pub const fn div_to<R: RoundingPolicy>(self, other: Self, scale: u8) -> Result<Self, Error> {
+        if scale > MAX_SCALE {
+            return Err(Error::InvalidInput(InvalidInputKind::ScaleTooLarge));
+        }
+        if other.unscaled == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        // quotient = self.unscaled * 10^(other.scale + scale - self.scale) / other.unscaled
+        let (high, low, divisor) = if other.scale + scale >= self.scale {
+            let (high, low) = widening_mul(self.unscaled as u128, pow10(other.scale + scale - self.scale));
+            (high, low, other.unscaled as u128)
+        } else {
+            let divisor = other.unscaled as u128 * pow10(self.scale - other.scale - scale);
+            (0, self.unscaled as u128, divisor)
+        };
+        let (quotient, remainder) = match wide_div(high, low, divisor) {
+            Some(value) => value,
+            None => return Err(Error::Overflow),
+        };
+        if quotient > u64::MAX as u128 {
+            return Err(Error::Overflow);
+        }
+        let quotient = quotient as u64;
+        let unscaled = if round_up(R::MODE, quotient & 1 == 1, remainder, divisor, false) {
+            match quotient.checked_add(1) {
+                Some(value) => value,
+                None => return Err(Error::Overflow),
+            }
+        } else {
+            quotient
+        };
+        Ok(Self { unscaled, scale })
+    }
+
+    /// Writes this decimal into `buffer` and returns the number of bytes written. The buffer must
+    /// be at least [`Self::REQUIRED_BUFFER_LEN`] bytes. Output includes trailing zeros to match the scale.
+    pub fn write_to(&self, buffer: &mut [u8]) -> usize {
+        #[cold]
+        #[inline(never)]
+        fn insufficient_buffer_len(len: usize, required: usize) -> ! {
+            panic!("provided buffer length {} is too small, requires at least {} bytes", len, required);
+        }
+
+        if Self::REQUIRED_BUFFER_LEN > buffer.len() {
+            insufficient_buffer_len(buffer.len(), Self::REQUIRED_BUFFER_LEN)
+        }
+
+        let factor = pow10(self.scale) as u64;
+        let (int_part, mut frac) = (self.unscaled / factor, self.unscaled % factor);
+
+        // Write the integer part into the tail of a scratch buffer, then copy it to the front.
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        let mut tmp = int_part;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (tmp % 10) as u8;
+            tmp /= 10;
+            if tmp == 0 {
+                break;
+            }
+        }
+        let mut pos = digits.len() - start;
+        buffer[..pos].copy_from_slice(&digits[start..]);
+
+        if self.scale > 0 {
+            buffer[pos] = b'.';
+            pos += 1;
+            let mut divisor = factor / 10;
+            for _ in 0..self.scale {
+                buffer[pos] = b'0' + (frac / divisor) as u8;
+                pos += 1;
+                frac %= divisor;
+                divisor /= 10;
+            }
+        }
+
+        pos
+    }
+}
+
+impl<S: ScaleMetrics> From<DecimalU64<S>> for DynDecimal {
+    #[inline]
+    fn from(value: DecimalU64<S>) -> Self {
+        Self::from_decimal(value)
+    }
+}
+
+impl<S: ScaleMetrics> TryFrom<DynDecimal> for DecimalU64<S> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: DynDecimal) -> Result<Self, Self::Error> {
+        value.to_decimal()
+    }
+}
+
+impl PartialEq for DynDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DynDecimal {}
+
+impl PartialOrd for DynDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DynDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (lhs, rhs, _) = self.align(*other);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl Hash for DynDecimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // strip trailing zeros so that numerically equal values hash the same
+        let (mut unscaled, mut scale) = (self.unscaled, self.scale);
+        while scale > 0 && unscaled % 10 == 0 {
+            unscaled /= 10;
+            scale -= 1;
+        }
+        if unscaled == 0 {
+            scale = 0;
+        }
+        unscaled.hash(state);
+        scale.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Error, InvalidInputKind};
+    use crate::round::{Floor, HalfUp};
+    use crate::{DecimalU64, DynDecimal, U2, U4, U8};
+    use rstest_macros::rstest;
+    use std::collections::HashSet;
+
+    #[rstest]
+    #[case("123.45", 12345, 2)]
+    #[case("123.450", 123450, 3)]
+    #[case("123", 123, 0)]
+    #[case("123.", 123, 0)]
+    #[case("0.0000000000000000001", 1, 19)]
+    #[case("18446744073709551615", u64::MAX, 0)]
+    fn should_parse_and_infer_scale(#[case] s: &str, #[case] unscaled: u64, #[case] scale: u8) {
+        let value = DynDecimal::from_str(s).unwrap();
+        assert_eq!(unscaled, value.unscaled());
+        assert_eq!(scale, value.scale());
+        assert_eq!(s.trim_end_matches('.'), value.to_string());
+    }
+
+    #[test]
+    fn should_err_on_invalid_input() {
+        assert!(matches!(DynDecimal::from_str("18446744073709551616"), Err(Error::Overflow)));
+        assert!(matches!(
+            DynDecimal::from_str("0.00000000000000000001"),
+            Err(Error::InvalidInput(InvalidInputKind::TooManyFractionalDigits))
+        ));
+        assert!(matches!(
+            DynDecimal::from_slice(&[[b'0', b'.'].as_slice(), &[b'0'; 300]].concat()),
+            Err(Error::InvalidInput(InvalidInputKind::TooManyFractionalDigits))
+        ));
+        assert!(matches!(
+            DynDecimal::from_str("1,5"),
+            Err(Error::InvalidInput(InvalidInputKind::InvalidCharacter(',')))
+        ));
+        let error = Err(Error::InvalidInput(InvalidInputKind::ScaleTooLarge));
+        assert_eq!(error, DynDecimal::new(1, 20));
+        assert_eq!(error, DynDecimal::ZERO.rescale(20));
+        assert_eq!(error, DynDecimal::ZERO.add_to::<HalfUp>(DynDecimal::ZERO, 20));
+        assert_eq!(error, DynDecimal::ZERO.sub_to::<HalfUp>(DynDecimal::ZERO, 20));
+        assert_eq!(error, DynDecimal::ZERO.mul_to::<HalfUp>(DynDecimal::ZERO, 20));
+        assert_eq!(error, DynDecimal::ZERO.div_to::<HalfUp>(DynDecimal::ZERO, 20));
+    }
+
+    #[test]
+    fn should_write_max_at_every_scale() {
+        let mut buffer = [0u8; DynDecimal::REQUIRED_BUFFER_LEN];
+        assert_eq!(20, DynDecimal::new(u64::MAX, 0).unwrap().write_to(&mut buffer));
+        assert_eq!(21, DynDecimal::new(u64::MAX, 8).unwrap().write_to(&mut buffer));
+        assert_eq!(21, DynDecimal::new(u64::MAX, 19).unwrap().write_to(&mut buffer));
+        assert_eq!("1.8446744073709551615", std::str::from_utf8(&buffer).unwrap());
+    }
+
+    #[rstest]
+    #[case("1.2050", 2, "1.21")]
+    #[case("1.2049", 2, "1.20")]
+    #[case("1.5", 4, "1.5000")]
+    #[case("9.5", 0, "10")]
+    fn should_rescale(#[case] input: &str, #[case] scale: u8, #[case] expected: &str) {
+        let value = DynDecimal::from_str(input).unwrap();
+        assert_eq!(expected, value.rescale(scale).unwrap().to_string());
+    }
+
+    #[rstest]
+    #[case("1.25", "0.005", 3, "1.255", "1.255")]
+    #[case("1.25", "0.005", 2, "1.25", "1.26")]
+    #[case("100", "0.00000001", 8, "100.00000001", "100.00000001")]
+    fn should_add_to(#[case] a: &str, #[case] b: &str, #[case] scale: u8, #[case] floor: &str, #[case] half_up: &str) {
+        let a = DynDecimal::from_str(a).unwrap();
+        let b = DynDecimal::from_str(b).unwrap();
+        assert_eq!(floor, a.add_to::<Floor>(b, scale).unwrap().to_string());
+        assert_eq!(half_up, a.add_to::<HalfUp>(b, scale).unwrap().to_string());
+    }
+
+    #[test]
+    fn should_sub_to() {
+        let a = DynDecimal::from_str("1.25").unwrap();
+        let b = DynDecimal::from_str("0.005").unwrap();
+        assert_eq!("1.245", a.sub_to::<HalfUp>(b, 3).unwrap().to_string());
+        assert_eq!("1.25", a.sub_to::<HalfUp>(b, 2).unwrap().to_string());
+        assert_eq!("1.24", a.sub_to::<Floor>(b, 2).unwrap().to_string());
+        assert!(matches!(b.sub_to::<HalfUp>(a, 3), Err(Error::Underflow)));
+    }
+
+    #[rstest]
+    #[case("101.25", "0.00012345", 7, "0.0124993", "0.0124993")]
+    #[case("101.25", "0.00012345", 10, "0.0124993125", "0.0124993125")]
+    #[case("101.25", "0.00012345", 3, "0.012", "0.012")]
+    #[case("1.25", "0.5", 1, "0.6", "0.6")]
+    #[case("1.25", "0.7", 1, "0.8", "0.9")]
+    #[case("2", "3", 4, "6.0000", "6.0000")]
+    fn should_mul_to(#[case] a: &str, #[case] b: &str, #[case] scale: u8, #[case] floor: &str, #[case] half_up: &str) {
+        let a = DynDecimal::from_str(a).unwrap();
+        let b = DynDecimal::from_str(b).unwrap();
+        assert_eq!(floor, a.mul_to::<Floor>(b, scale).unwrap().to_string());
+        assert_eq!(half_up, a.mul_to::<HalfUp>(b, scale).unwrap().to_string());
+    }
+
+    #[rstest]
+    #[case("1", "3", 8, "0.33333333", "0.33333333")]
+    #[case("2", "3", 8, "0.66666666", "0.66666667")]
+    #[case("0.00000001", "100", 10, "0.0000000001", "0.0000000001")]
+    #[case("50000", "0.2", 0, "250000", "250000")]
+    #[case("12.3456", "2", 1, "6.1", "6.2")]
+    fn should_div_to(#[case] a: &str, #[case] b: &str, #[case] scale: u8, #[case] floor: &str, #[case] half_up: &str) {
+        let a = DynDecimal::from_str(a).unwrap();
+        let b = DynDecimal::from_str(b).unwrap();
+        assert_eq!(floor, a.div_to::<Floor>(b, scale).unwrap().to_string());
+        assert_eq!(half_up, a.div_to::<HalfUp>(b, scale).unwrap().to_string());
+    }
+
+    #[test]
+    fn should_err_on_arithmetic_overflow() {
+        let max = DynDecimal::new(u64::MAX, 0).unwrap();
+        let half = DynDecimal::from_str("0.5").unwrap();
+        assert!(matches!(max.add_to::<HalfUp>(max, 0), Err(Error::Overflow)));
+        assert!(matches!(max.mul_to::<HalfUp>(max, 0), Err(Error::Overflow)));
+        assert!(matches!(max.div_to::<HalfUp>(half, 0), Err(Error::Overflow)));
+        assert!(matches!(max.div_to::<HalfUp>(DynDecimal::ZERO, 0), Err(Error::DivisionByZero)));
+        // rounding the last digit up is what overflows
+        assert_eq!(max, max.add_to::<Floor>(half, 0).unwrap());
+        assert!(matches!(max.add_to::<HalfUp>(half, 0), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn should_compare_numerically() {
+        let a = DynDecimal::from_str("1.5").unwrap();
+        let b = DynDecimal::from_str("1.50").unwrap();
+        let c = DynDecimal::from_str("1.49999").unwrap();
+        assert_eq!(a, b);
+        assert!(c < a);
+        assert_eq!(DynDecimal::ZERO, DynDecimal::from_str("0.000").unwrap());
+
+        let set: HashSet<DynDecimal> = [a, b, DynDecimal::ZERO, DynDecimal::from_str("0.0").unwrap()].into();
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn should_convert_to_and_from_decimal() {
+        let value = DecimalU64::<U4>::from_str("12.3456").unwrap();
+        let dynamic = DynDecimal::from(value);
+        assert_eq!(dynamic, DynDecimal::from_decimal(value));
+        assert_eq!(4, dynamic.scale());
+        assert_eq!("12.3456", dynamic.to_string());
+
+        assert_eq!(value, DecimalU64::<U4>::try_from(dynamic).unwrap());
+        assert_eq!("12.34560000", dynamic.to_decimal::<U8>().unwrap().to_string());
+        assert_eq!("12.35", dynamic.to_decimal::<U2>().unwrap().to_string());
+        assert!(matches!(DynDecimal::new(u64::MAX, 0).unwrap().to_decimal::<U2>(), Err(Error::Overflow)));
+    }
+}
@@ -6,6 +6,12 @@ pub enum Error {
     InvalidInput(InvalidInputKind),
     #[error("overflow")]
     Overflow,
+    #[error("underflow")]
+    Underflow,
+    #[error("division by zero")]
+    DivisionByZero,
+    #[error("inexact result")]
+    Inexact,
 }
 
 #[derive(Error, Debug, Copy, Clone, Eq, PartialEq)]
@@ -18,4 +24,12 @@ pub enum InvalidInputKind {
     InfiniteNumber,
     #[error("value less than one")]
     LessThanOne,
+    #[error("zero value")]
+    Zero,
+    #[error("too many fractional digits")]
+    TooManyFractionalDigits,
+    #[error("root degree too large")]
+    DegreeTooLarge,
+    #[error("scale too large")]
+    ScaleTooLarge,
 }
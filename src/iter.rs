@@ -0,0 +1,100 @@
+//! Overflow-aware aggregation over iterators of decimals.
+//!
+//! Sums are accumulated in `u128`, which cannot overflow for any practical number of `u64` addends, and
+//! narrowed back once at the end. The loop body is a plain widening add without branches, so the compiler
+//! is free to vectorise it over slices.
+
+use crate::error::Error;
+use crate::{DecimalU64, ScaleMetrics};
+use std::borrow::Borrow;
+
+/// Extension trait for iterators over [`DecimalU64`] values or references to them.
+///
+/// # Example
+/// ```no_run
+/// use decimal64::iter::DecimalIterator;
+/// use decimal64::{DecimalU64, U8};
+///
+/// let quantities = [DecimalU64::<U8>::MAX, DecimalU64::ONE];
+/// assert_eq!(None, quantities.iter().checked_sum());
+/// assert_eq!(DecimalU64::<U8>::MAX, quantities.iter().saturating_sum());
+/// ```
+pub trait DecimalIterator<S: ScaleMetrics>: Iterator + Sized {
+    /// Sums all values, returning `None` if the total does not fit.
+    fn checked_sum(self) -> Option<DecimalU64<S>> {
+        self.wide_sum().ok()
+    }
+
+    /// Sums all values, saturating at `MAX`.
+    fn saturating_sum(self) -> DecimalU64<S> {
+        self.wide_sum().unwrap_or(DecimalU64::MAX)
+    }
+
+    /// Sums all values in `u128` and narrows the total back, returning [`Error::Overflow`] if it does
+    /// not fit.
+    fn wide_sum(self) -> Result<DecimalU64<S>, Error>;
+
+    /// Multiplies all values, truncating like `Mul` after every step. Returns `None` as soon as an
+    /// intermediate product does not fit.
+    fn checked_product(self) -> Option<DecimalU64<S>>;
+}
+
+impl<S, I> DecimalIterator<S> for I
+where
+    S: ScaleMetrics,
+    I: Iterator,
+    I::Item: Borrow<DecimalU64<S>>,
+{
+    #[inline]
+    fn wide_sum(self) -> Result<DecimalU64<S>, Error> {
+        let sum = self.fold(0u128, |sum, value| sum + value.borrow().0 as u128);
+        match u64::try_from(sum) {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(_) => Err(Error::Overflow),
+        }
+    }
+
+    #[inline]
+    fn checked_product(mut self) -> Option<DecimalU64<S>> {
+        self.try_fold(DecimalU64::ONE, |product, value| product.checked_mul(DecimalU64::new(value.borrow().0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::iter::DecimalIterator;
+    use crate::{DecimalU64, U8};
+
+    type D = DecimalU64<U8>;
+
+    #[test]
+    fn should_sum() {
+        let values = [D::ONE, D::TWO, D::from_str("0.5").unwrap()];
+        assert_eq!(Some(D::from_str("3.5").unwrap()), values.iter().checked_sum());
+        assert_eq!(D::from_str("3.5").unwrap(), values.iter().saturating_sum());
+        assert_eq!(Ok(D::from_str("3.5").unwrap()), values.into_iter().wide_sum());
+    }
+
+    #[test]
+    fn should_sum_empty() {
+        let values: [D; 0] = [];
+        assert_eq!(Some(D::ZERO), values.iter().checked_sum());
+        assert_eq!(Some(D::ONE), values.iter().checked_product());
+    }
+
+    #[test]
+    fn should_detect_overflow() {
+        let mut values = [D::MAX, D::ONE, D::MAX];
+        assert_eq!(None, values.iter().checked_sum());
+        assert_eq!(D::MAX, values.iter_mut().saturating_sum());
+        assert_eq!(Err(Error::Overflow), values.iter().wide_sum());
+    }
+
+    #[test]
+    fn should_multiply() {
+        let values = [D::TWO, D::THREE, D::from_str("0.5").unwrap()];
+        assert_eq!(Some(D::THREE), values.iter().checked_product());
+        assert_eq!(None, [D::MAX, D::TWO].into_iter().checked_product());
+    }
+}
@@ -1,15 +1,36 @@
 use crate::error::{Error, InvalidInputKind};
+use crate::round::HalfUp;
 use std::fmt::{Display, Formatter};
 use std::marker::PhantomData;
 
 mod arithmetic;
+mod cmp;
+mod dynamic;
 pub mod error;
+pub mod iter;
 mod macros;
 pub mod math;
 pub mod round;
 #[cfg(feature = "serde")]
 pub mod serde;
-
+mod signed;
+mod wide;
+
+pub use crate::arithmetic::{MaxScale, Residual};
+pub use crate::dynamic::DynDecimal;
+pub use crate::signed::DecimalI64;
+pub use crate::wide::DecimalU128;
+
+/// Describes the number of fractional digits of a decimal type.
+///
+/// The built-in scales go from [`U0`] to [`U18`]. The integer range shrinks by a factor of ten with
+/// every extra fractional digit, so while `DecimalU64<U8>` tops out at about 184 billion, `DecimalU64<U18>`
+/// can only represent values up to `18.446744073709551615`. Scale 19 is the largest one whose factor
+/// still fits into `u64`, but it leaves less than two whole units and is therefore not provided.
+///
+/// Implementations are checked at compile time once used with [`DecimalU64`]: `SCALE` must not exceed
+/// [`MAX_SCALE`], `SCALE_FACTOR` must equal `10^SCALE` and `REQUIRED_BUFFER_LEN` must match
+/// [`required_buffer_len`]. Prefer [`gen_scale!`] over implementing this trait by hand.
 pub trait ScaleMetrics {
     const SCALE: u8;
     const SCALE_FACTOR: u64;
@@ -25,14 +46,99 @@ gen_scale!(U5, 5, 21);
 gen_scale!(U6, 6, 21);
 gen_scale!(U7, 7, 21);
 gen_scale!(U8, 8, 21);
+gen_scale!(U9, 9, 21);
+gen_scale!(U10, 10, 21);
+gen_scale!(U11, 11, 21);
+gen_scale!(U12, 12, 21);
+gen_scale!(U13, 13, 21);
+gen_scale!(U14, 14, 21);
+gen_scale!(U15, 15, 21);
+gen_scale!(U16, 16, 21);
+gen_scale!(U17, 17, 21);
+gen_scale!(U18, 18, 21);
+
+// Sized to cover scale 19, the largest power of ten that fits into u64.
+const SCALE_FACTORS: [u64; 20] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+    10_000_000_000,
+    100_000_000_000,
+    1_000_000_000_000,
+    10_000_000_000_000,
+    100_000_000_000_000,
+    1_000_000_000_000_000,
+    10_000_000_000_000_000,
+    100_000_000_000_000_000,
+    1_000_000_000_000_000_000,
+    10_000_000_000_000_000_000,
+];
+const POW5_U128: [u128; 20] = [
+    1,
+    5,
+    25,
+    125,
+    625,
+    3_125,
+    15_625,
+    78_125,
+    390_625,
+    1_953_125,
+    9_765_625,
+    48_828_125,
+    244_140_625,
+    1_220_703_125,
+    6_103_515_625,
+    30_517_578_125,
+    152_587_890_625,
+    762_939_453_125,
+    3_814_697_265_625,
+    19_073_486_328_125,
+];
+
+/// Largest scale supported by [`ScaleMetrics`], as `10^19` is the largest power of ten that fits into `u64`.
+pub const MAX_SCALE: u8 = 19;
+
+/// Number of bytes required to format `u64::MAX` with `scale` fractional digits.
+///
+/// # Example
+/// ```no_run
+/// use decimal64::required_buffer_len;
+///
+/// assert_eq!(20, required_buffer_len(0));
+/// assert_eq!(21, required_buffer_len(8));
+/// ```
+pub const fn required_buffer_len(scale: u8) -> usize {
+    let mut int_part = u64::MAX / SCALE_FACTORS[scale as usize];
+    let mut len = 1;
+    while int_part >= 10 {
+        int_part /= 10;
+        len += 1;
+    }
+    if scale > 0 { len + 1 + scale as usize } else { len }
+}
 
-const SCALE_FACTORS: [u64; 9] = [1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000];
-const POW5_U128: [u128; 9] = [1, 5, 25, 125, 625, 3125, 15625, 78125, 390625];
-
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// Unsigned fixed point decimal backed by `u64` with the scale given by `S`.
+///
+/// Equality and ordering are numeric and also available between different scales, so `1.5` at [`U1`]
+/// equals `1.50` at [`U2`].
+#[derive(Debug, Copy, Clone)]
 #[repr(transparent)]
 pub struct DecimalU64<S>(pub u64, PhantomData<S>);
 
+impl<S: ScaleMetrics> Default for DecimalU64<S> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
 impl<S: ScaleMetrics> Display for DecimalU64<S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // A buffer large enough for our formatted value.
@@ -45,8 +151,19 @@ impl<S: ScaleMetrics> Display for DecimalU64<S> {
 }
 
 impl<S: ScaleMetrics> DecimalU64<S> {
+    /// Rejects inconsistent [`ScaleMetrics`] implementations, referencing it forces the evaluation.
+    pub(crate) const VALID_SCALE: () = {
+        assert!(S::SCALE <= MAX_SCALE, "scale must not exceed 19");
+        assert!(S::SCALE_FACTOR == SCALE_FACTORS[S::SCALE as usize], "scale factor must be 10^SCALE");
+        assert!(
+            S::REQUIRED_BUFFER_LEN == required_buffer_len(S::SCALE),
+            "required buffer length does not match the scale"
+        );
+    };
+
     #[inline]
     pub const fn new(unscaled: u64) -> Self {
+        let () = Self::VALID_SCALE;
         Self(unscaled, PhantomData)
     }
 
@@ -112,7 +229,7 @@ impl<S: ScaleMetrics> DecimalU64<S> {
             None => return Err(Error::Overflow),
         };
 
-        Ok(Self(unscaled, PhantomData))
+        Ok(Self::new(unscaled))
     }
 
     /// Parses a decimal from a UTF-8 string slice.
@@ -162,6 +279,44 @@ impl<S: ScaleMetrics> DecimalU64<S> {
     /// assert_eq!("12.35", value.to_string());
     /// ```
     pub const fn from_f64(value: f64) -> Result<Self, Error> {
+        let (unscaled, remainder, shift) = match Self::from_f64_truncated(value) {
+            Ok(parts) => parts,
+            Err(err) => return Err(err),
+        };
+        // a dropped fraction with a denominator beyond u128 cannot reach half
+        if remainder != 0 && shift < 128 && (remainder << 1) >= (1u128 << shift) {
+            return match unscaled.checked_add(1) {
+                Some(value) => Ok(DecimalU64::new(value)),
+                None => Err(Error::Overflow),
+            };
+        }
+        Ok(DecimalU64::new(unscaled))
+    }
+
+    /// Creates a decimal from `f64` only if its binary value is representable at the target scale
+    /// without rounding. Returns [`Error::Inexact`] otherwise.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::error::Error;
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// assert_eq!("12.25", DecimalU64::<U2>::from_f64_exact(12.25).unwrap().to_string());
+    /// // 0.1 has no finite binary representation
+    /// assert_eq!(Err(Error::Inexact), DecimalU64::<U2>::from_f64_exact(0.1));
+    /// ```
+    pub const fn from_f64_exact(value: f64) -> Result<Self, Error> {
+        match Self::from_f64_truncated(value) {
+            Ok((unscaled, 0, _)) => Ok(DecimalU64::new(unscaled)),
+            Ok(_) => Err(Error::Inexact),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Converts `value` at the target scale, returning the truncated unscaled value together with the
+    /// dropped binary fraction `remainder / 2^shift`. A `shift` of 128 or more means nothing but the
+    /// fraction is left.
+    const fn from_f64_truncated(value: f64) -> Result<(u64, u128, u32), Error> {
         const EXP_BITS: u64 = 0x7ff;
         const EXP_BIAS: i32 = 1023;
         const MANTISSA_BITS: u32 = 52;
@@ -179,7 +334,7 @@ impl<S: ScaleMetrics> DecimalU64<S> {
             return Err(Error::InvalidInput(InvalidInputKind::NegativeNumber));
         }
         if exp_bits == 0 && frac_bits == 0 {
-            return Ok(Self::ZERO);
+            return Ok((0, 0, 0));
         }
 
         let (mantissa, exp2) = if exp_bits == 0 {
@@ -201,35 +356,23 @@ impl<S: ScaleMetrics> DecimalU64<S> {
             if base > (u64::MAX as u128 >> shift) {
                 return Err(Error::Overflow);
             }
-            let unscaled = base << shift;
-            Ok(DecimalU64::new(unscaled as u64))
+            Ok(((base << shift) as u64, 0, 0))
         } else {
             let shift = (-exp2) as u32;
             if shift >= 128 {
-                // Denominator exceeds u128; remainder cannot reach half, so this rounds to zero.
-                return Ok(Self::ZERO);
-            }
-            let denom = 1u128 << shift;
-            let mut unscaled = base / denom;
-            let remainder = base % denom;
-
-            if remainder != 0 && (remainder << 1) >= denom {
-                if unscaled == u64::MAX as u128 {
-                    return Err(Error::Overflow);
-                }
-                unscaled += 1;
+                return Ok((0, base, shift));
             }
-
+            let unscaled = base >> shift;
             if unscaled > u64::MAX as u128 {
                 return Err(Error::Overflow);
             }
-
-            Ok(DecimalU64::new(unscaled as u64))
+            Ok((unscaled as u64, base & ((1u128 << shift) - 1), shift))
         }
     }
 
     /// Rescales this decimal to a different scale, returning an error on overflow.
-    /// Downscaling rounds half-up when fractional digits are dropped.
+    /// Downscaling rounds half-up when fractional digits are dropped, see [`Self::rescale_with`] for
+    /// other rounding policies.
     ///
     /// # Example
     /// Scale up (will error on overflow).
@@ -252,37 +395,53 @@ impl<S: ScaleMetrics> DecimalU64<S> {
     /// assert_eq!("1.21", downscaled.to_string());
     /// ```
     pub const fn rescale<T: ScaleMetrics>(&self) -> Result<DecimalU64<T>, self::Error> {
+        self.rescale_with::<T, HalfUp>()
+    }
+
+    /// Rescales this decimal to a different scale, returning [`Error::Inexact`] if any non-zero digit
+    /// would be dropped and [`Error::Overflow`] if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::error::Error;
+    /// use decimal64::{DecimalU64, U2, U4};
+    ///
+    /// let amount = DecimalU64::<U4>::from_str("1.2000").unwrap();
+    /// assert_eq!("1.20", amount.rescale_exact::<U2>().unwrap().to_string());
+    /// let amount = DecimalU64::<U4>::from_str("1.2050").unwrap();
+    /// assert_eq!(Err(Error::Inexact), amount.rescale_exact::<U2>());
+    /// ```
+    pub const fn rescale_exact<T: ScaleMetrics>(&self) -> Result<DecimalU64<T>, Error> {
+        match self.rescale_with_residual::<T>() {
+            Ok((value, residual)) if residual.0 == 0 => Ok(value),
+            Ok(_) => Err(Error::Inexact),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Rescales this decimal to a different scale, truncating the dropped digits and returning them as
+    /// the residual at the original scale, so that `value + residual` equals this decimal exactly.
+    /// Returns [`Error::Overflow`] if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2, U4};
+    ///
+    /// let amount = DecimalU64::<U4>::from_str("1.2050").unwrap();
+    /// let (value, residual) = amount.rescale_with_residual::<U2>().unwrap();
+    /// assert_eq!("1.20", value.to_string());
+    /// assert_eq!("0.0050", residual.to_string());
+    /// ```
+    pub const fn rescale_with_residual<T: ScaleMetrics>(&self) -> Result<(DecimalU64<T>, Self), Error> {
         if T::SCALE >= S::SCALE {
-            // upscale
-            let factor = match 10u64.checked_pow((T::SCALE - S::SCALE) as u32) {
-                Some(value) => value,
-                None => return Err(Error::Overflow),
-            };
-            let unscaled = match self.0.checked_mul(factor) {
+            let unscaled = match self.0.checked_mul(SCALE_FACTORS[(T::SCALE - S::SCALE) as usize]) {
                 Some(value) => value,
                 None => return Err(Error::Overflow),
             };
-
-            Ok(DecimalU64::<T>::new(unscaled))
+            Ok((DecimalU64::new(unscaled), Self::ZERO))
         } else {
-            // downscale
-            let factor = match 10u64.checked_pow((S::SCALE - T::SCALE) as u32) {
-                Some(value) => value,
-                None => return Err(Error::Overflow),
-            };
-            let truncated = self.0 / factor;
-            let remainder = self.0 % factor;
-            let mut rounded = truncated;
-            if remainder != 0 {
-                let double = (remainder as u128) * 2;
-                if double >= factor as u128 {
-                    rounded = match truncated.checked_add(1) {
-                        Some(value) => value,
-                        None => return Err(Error::Overflow),
-                    };
-                }
-            }
-            Ok(DecimalU64::<T>::new(rounded))
+            let factor = SCALE_FACTORS[(S::SCALE - T::SCALE) as usize];
+            Ok((DecimalU64::new(self.0 / factor), Self::new(self.0 % factor)))
         }
     }
 
@@ -339,6 +498,8 @@ impl<S: ScaleMetrics> DecimalU64<S> {
             panic!("provided buffer length {} is too small, requires at least {} bytes", len, required);
         }
 
+        let () = Self::VALID_SCALE;
+
         // ensure the provided buffer has enough length to write the max value
         if S::REQUIRED_BUFFER_LEN > buffer.len() {
             insufficient_buffer_len(buffer.len(), S::REQUIRED_BUFFER_LEN)
@@ -682,12 +843,93 @@ mod tests {
         let mut buffer = [0u8; U8::REQUIRED_BUFFER_LEN];
         DecimalU64::<U8>::MAX.write_to(&mut buffer);
     }
+
+    #[test]
+    fn should_use_constant_max_for_high_scales() {
+        assert_eq!("18446744073.709551615", DecimalU64::<U9>::MAX.to_string());
+        assert_eq!("1844674407.3709551615", DecimalU64::<U10>::MAX.to_string());
+        assert_eq!("184467440.73709551615", DecimalU64::<U11>::MAX.to_string());
+        assert_eq!("18446744.073709551615", DecimalU64::<U12>::MAX.to_string());
+        assert_eq!("1844674.4073709551615", DecimalU64::<U13>::MAX.to_string());
+        assert_eq!("184467.44073709551615", DecimalU64::<U14>::MAX.to_string());
+        assert_eq!("18446.744073709551615", DecimalU64::<U15>::MAX.to_string());
+        assert_eq!("1844.6744073709551615", DecimalU64::<U16>::MAX.to_string());
+        assert_eq!("184.46744073709551615", DecimalU64::<U17>::MAX.to_string());
+        assert_eq!("18.446744073709551615", DecimalU64::<U18>::MAX.to_string());
+        assert_eq!("10.000000000000000000", DecimalU64::<U18>::TEN.to_string());
+    }
+
+    #[test]
+    fn should_write_max_to_buffer_of_exact_size_for_high_scales() {
+        fn write_max<S: ScaleMetrics>() -> usize {
+            let mut buffer = [0u8; 21];
+            DecimalU64::<S>::MAX.write_to(&mut buffer[..S::REQUIRED_BUFFER_LEN])
+        }
+
+        assert_eq!(21, write_max::<U9>());
+        assert_eq!(21, write_max::<U12>());
+        assert_eq!(21, write_max::<U15>());
+        assert_eq!(21, write_max::<U18>());
+    }
+
+    #[test]
+    fn should_parse_at_high_scales() -> anyhow::Result<()> {
+        assert_eq!(1_234_000_000_000_000_000, DecimalU64::<U18>::from_str("1.234")?.0);
+        assert_eq!(u64::MAX, DecimalU64::<U18>::from_str("18.446744073709551615")?.0);
+        assert_eq!(1, DecimalU64::<U18>::from_str("0.000000000000000001")?.0);
+        assert!(matches!(DecimalU64::<U18>::from_str("18.446744073709551616"), Err(Error::Overflow)));
+        assert!(matches!(DecimalU64::<U18>::from_str("19"), Err(Error::Overflow)));
+        assert!(matches!(DecimalU64::<U12>::from_str("0.0000000000001"), Err(Error::Overflow)));
+        assert_eq!("123456.789012345678", DecimalU64::<U12>::from_str("123456.789012345678")?.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn should_create_from_f64_at_high_scales() -> anyhow::Result<()> {
+        assert_eq!("0.125000000000000000", DecimalU64::<U18>::from_f64(0.125)?.to_string());
+        assert_eq!("12.250000000000", DecimalU64::<U12>::from_f64(12.25)?.to_string());
+        // 0.1 is not exactly representable, the nearest f64 is 0.1000000000000000055511151231257827...
+        assert_eq!("0.100000000000000006", DecimalU64::<U18>::from_f64(0.1)?.to_string());
+        assert!(matches!(DecimalU64::<U18>::from_f64(20.0), Err(Error::Overflow)));
+        Ok(())
+    }
+
+    #[test]
+    fn should_compute_required_buffer_len() {
+        assert_eq!(U0::REQUIRED_BUFFER_LEN, required_buffer_len(0));
+        assert_eq!(U1::REQUIRED_BUFFER_LEN, required_buffer_len(1));
+        assert_eq!(U8::REQUIRED_BUFFER_LEN, required_buffer_len(8));
+        assert_eq!(U18::REQUIRED_BUFFER_LEN, required_buffer_len(18));
+        assert_eq!(21, required_buffer_len(MAX_SCALE));
+    }
+
+    #[test]
+    fn should_derive_buffer_len_in_gen_scale() {
+        gen_scale!(Cents, 2);
+
+        assert_eq!(21, Cents::REQUIRED_BUFFER_LEN);
+        assert_eq!(100, Cents::SCALE_FACTOR);
+        assert_eq!("12.34", DecimalU64::<Cents>::from_str("12.34").unwrap().to_string());
+    }
+
+    #[test]
+    fn should_support_user_defined_scale_19() -> anyhow::Result<()> {
+        gen_scale!(U19, 19);
+
+        let value = DecimalU64::<U19>::from_str("1.5")?;
+        assert_eq!(15_000_000_000_000_000_000, value.0);
+        assert_eq!("1.5000000000000000000", value.to_string());
+        assert_eq!(value, DecimalU64::<U19>::from_f64(1.5)?);
+        assert_eq!("1.50", value.rescale::<U2>()?.to_string());
+        assert_eq!("1.8446744073709551615", DecimalU64::<U19>::MAX.to_string());
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod rescale_tests {
     use crate::error::Error;
-    use crate::{DecimalU64, ScaleMetrics, U0, U1, U2, U3, U4, U5, U7, U8};
+    use crate::{DecimalU64, ScaleMetrics, U0, U1, U2, U3, U4, U5, U7, U8, U17, U18};
     use rstest_macros::rstest;
 
     // Generic rescale test for checked rescale when no rounding is needed.
@@ -787,6 +1029,22 @@ mod rescale_tests {
         assert_eq!("101.21", result.to_string());
     }
 
+    #[test]
+    fn should_rescale_between_high_scales() -> anyhow::Result<()> {
+        let d = DecimalU64::<U2>::from_str("12.34")?;
+        let up = d.rescale::<U18>()?;
+        assert_eq!("12.340000000000000000", up.to_string());
+        assert_eq!(d, up.rescale::<U2>()?);
+
+        let d = DecimalU64::<U18>::from_str("1.000000000000000005")?;
+        assert_eq!("1.00000000000000001", d.rescale::<U17>()?.to_string());
+        assert_eq!("1", d.rescale::<U0>()?.to_string());
+        assert_eq!("18", DecimalU64::<U18>::MAX.rescale::<U0>()?.to_string());
+
+        assert!(matches!(DecimalU64::<U2>::from_str("18.45")?.rescale::<U18>(), Err(Error::Overflow)));
+        Ok(())
+    }
+
     #[test]
     fn should_error_on_overflow() {
         // Try to upscale MAX value at U0 to U1 (would multiply by 10, causing overflow)
@@ -810,6 +1068,29 @@ mod rescale_tests {
         let result: Result<DecimalU64<U1>, Error> = too_big.rescale();
         assert!(matches!(result, Err(Error::Overflow)));
     }
+
+    #[rstest]
+    #[case("1.2000", Ok("1.20"), "0.0000")]
+    #[case("1.2050", Err(Error::Inexact), "0.0050")]
+    #[case("1.2099", Err(Error::Inexact), "0.0099")]
+    #[case("0.0001", Err(Error::Inexact), "0.0001")]
+    fn should_rescale_exact(#[case] input: &str, #[case] expected: Result<&str, Error>, #[case] residual: &str) {
+        let d = DecimalU64::<U4>::from_str(input).unwrap();
+        let expected = expected.map(|value| DecimalU64::<U2>::from_str(value).unwrap());
+        assert_eq!(expected, d.rescale_exact::<U2>());
+        let (value, rest) = d.rescale_with_residual::<U2>().unwrap();
+        assert_eq!(residual, rest.to_string());
+        assert_eq!(d, value.add_to(rest));
+    }
+
+    #[test]
+    fn should_rescale_exact_up() -> anyhow::Result<()> {
+        let d = DecimalU64::<U2>::from_str("12.34")?;
+        assert_eq!(Ok(DecimalU64::<U4>::from_str("12.34")?), d.rescale_exact::<U4>());
+        assert_eq!(Ok((d, DecimalU64::ZERO)), d.rescale_with_residual::<U2>());
+        assert_eq!(Err(Error::Overflow), DecimalU64::<U2>::MAX.rescale_exact::<U4>());
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -843,6 +1124,17 @@ mod f64_tests {
         Ok(())
     }
 
+    #[test]
+    fn should_create_from_f64_exact() -> anyhow::Result<()> {
+        assert_eq!("12.25", DecimalU64::<U2>::from_f64_exact(12.25)?.to_string());
+        assert_eq!(DecimalU64::<U2>::ZERO, DecimalU64::<U2>::from_f64_exact(0.0)?);
+        assert_eq!(Err(Error::Inexact), DecimalU64::<U2>::from_f64_exact(0.125));
+        assert_eq!(Err(Error::Inexact), DecimalU64::<U2>::from_f64_exact(0.1));
+        assert_eq!(Err(Error::Inexact), DecimalU64::<U2>::from_f64_exact(f64::MIN_POSITIVE));
+        assert_eq!(Err(Error::Overflow), DecimalU64::<U2>::from_f64_exact(1e30));
+        Ok(())
+    }
+
     #[test]
     fn should_error_on_from_f64_infinity() {
         let err = DecimalU64::<U2>::from_f64(f64::INFINITY);
@@ -1,13 +1,68 @@
+/// Defines a unit struct implementing [`ScaleMetrics`](crate::ScaleMetrics) for the given scale.
+///
+/// The buffer length can be omitted, in which case it is derived from the scale. Every path is fully
+/// qualified, so the macro can be used from downstream crates without importing anything. Scales are
+/// validated at compile time as soon as they are used with [`DecimalU64`](crate::DecimalU64).
+///
+/// # Example
+/// ```no_run
+/// use decimal64::{DecimalU64, gen_scale};
+///
+/// gen_scale!(Satoshi, 8);
+///
+/// let value = DecimalU64::<Satoshi>::from_str("0.00000001").unwrap();
+/// assert_eq!(1, value.0);
+/// ```
+///
+/// Inconsistent definitions are rejected.
+/// ```compile_fail
+/// use decimal64::{DecimalU64, gen_scale};
+///
+/// gen_scale!(Broken, 8, 16);
+///
+/// let value = DecimalU64::<Broken>::from_str("1").unwrap();
+/// ```
 #[macro_export]
 macro_rules! gen_scale {
+    ($struct_name:ident, $scale:expr) => {
+        $crate::gen_scale!($struct_name, $scale, $crate::required_buffer_len($scale));
+    };
     ($struct_name:ident, $scale:expr, $buffer_len:expr) => {
-        #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::default::Default,
+            ::core::marker::Copy,
+            ::core::clone::Clone,
+            ::core::cmp::Eq,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Ord,
+            ::core::cmp::PartialOrd,
+            ::core::hash::Hash,
+        )]
         pub struct $struct_name;
 
-        impl ScaleMetrics for $struct_name {
+        impl $crate::ScaleMetrics for $struct_name {
             const SCALE: u8 = $scale;
             const SCALE_FACTOR: u64 = 10u64.pow(Self::SCALE as u32);
             const REQUIRED_BUFFER_LEN: usize = $buffer_len;
         }
     };
 }
+
+/// Selects the overflow behaviour of an operator. With the `strict` feature the checked expression is
+/// evaluated and `None` panics with `$message` in every build profile, otherwise the unchecked expression
+/// keeps the speed and semantics of plain integer arithmetic.
+macro_rules! strict_op {
+    ($checked:expr, $unchecked:expr, $message:literal) => {
+        if cfg!(feature = "strict") {
+            match $checked {
+                Some(value) => value,
+                None => panic!($message),
+            }
+        } else {
+            $unchecked
+        }
+    };
+}
+
+pub(crate) use strict_op;
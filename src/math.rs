@@ -1,33 +1,62 @@
+//! Transcendental functions evaluated in integer arithmetic.
+//!
+//! All computations are carried out with 18 fractional digits internally and rounded half-up to the
+//! scale of the value at the end. Up to about [`U15`](crate::U15) results are accurate to the last
+//! digit, at [`U18`](crate::U18) `ln` stays within 24 and `exp` within 64 units in the last place.
+//! `powd` inherits the bounds of `exp`. Integer powers and roots are instead evaluated at the scale of
+//! the value with a selectable rounding policy, see [`DecimalU64::checked_pow`] and
+//! [`DecimalU64::nth_root`].
+//!
+//! The logarithms `ln_signed`, `log10`, `log2` and `log_base` accept values below one and return a
+//! [`DecimalI64`]. Their results are rounded half away from zero and bounded as follows:
+//!
+//! | scale        | `ln_signed` | `log10` | `log2` |
+//! |--------------|-------------|---------|--------|
+//! | up to `U12`  | 0.5 ULP     | 0.5 ULP | 0.5 ULP |
+//! | `U15`        | 1 ULP       | 1 ULP   | 1 ULP  |
+//! | `U18`        | 25 ULP      | 12 ULP  | 35 ULP |
+//!
+//! `log_base` divides by `ln(base)` and inherits the bound of `ln_signed` scaled by `1 / |ln(base)|`.
+//!
+//! Where the last digit matters, [`DecimalU64::exp_rounded`] and [`DecimalU64::ln_rounded`] are correctly
+//! rounded at every scale by a selectable [`RoundingPolicy`]. They evaluate with up to [`MAX_PRECISION`]
+//! digits and a known error bound, and only return once both ends of that bound round the same way.
+//! Arguments close enough to zero for `exp`, or to one for `ln`, that the result stays within half a unit
+//! of `1 + x` or `x` are rounded from the bounds of the series instead, as no working precision could
+//! separate them from the rounding boundary.
+//! [`DecimalU64::exp_with`] and [`DecimalU64::ln_with`] take the working precision explicitly and return
+//! [`Error::Inexact`] if it does not decide the rounding.
+//!
+//! For hot paths, [`DecimalU64::exp_fast`] and [`DecimalU64::ln_fast`] work from small precomputed tables in
+//! binary fixed point without any division. `ln_fast` stays within one ULP at every scale. `exp_fast` adds a
+//! relative error below `2^-61` to rounding half-up, which stays below one ULP for unscaled results up to
+//! `10^18` and below 9 ULP up to `u64::MAX`.
+
 use crate::error::{Error, InvalidInputKind};
-use crate::{DecimalU64, ScaleMetrics};
+use crate::round::{Floor, RoundingMode, RoundingPolicy, round_up};
+use crate::wide::{wide_div, widening_mul};
+use crate::{DecimalI64, DecimalU64, ScaleMetrics};
 
 const INTERNAL_SCALE: u32 = 18;
 const INTERNAL_FACTOR: u128 = 1_000_000_000_000_000_000;
 const LN2_INTERNAL: u128 = 693_147_180_559_945_309;
+const LN10_INTERNAL: u128 = 2_302_585_092_994_045_684;
+// Upper bounds on the number of series terms, both series stop early once the terms vanish at the
+// internal scale. The ln series converges slowest just below 2 where z ~ 1/3 and needs 20 terms to
+// stay accurate to 18 digits.
 const EXP_TERMS: u32 = 18;
-const LN_TERMS: u32 = 12;
-
-const POW10_U128: [u128; 19] = [
-    1,
-    10,
-    100,
-    1_000,
-    10_000,
-    100_000,
-    1_000_000,
-    10_000_000,
-    100_000_000,
-    1_000_000_000,
-    10_000_000_000,
-    100_000_000_000,
-    1_000_000_000_000,
-    10_000_000_000_000,
-    100_000_000_000_000,
-    1_000_000_000_000_000,
-    10_000_000_000_000_000,
-    100_000_000_000_000_000,
-    1_000_000_000_000_000_000,
-];
+const LN_TERMS: u32 = 20;
+
+/// `10^n` for every `n` up to 38.
+pub(crate) const POW10_U128: [u128; 39] = {
+    let mut table = [1u128; 39];
+    let mut n = 1;
+    while n < 39 {
+        table[n] = table[n - 1] * 10;
+        n += 1;
+    }
+    table
+};
 
 const fn scale_to_internal(unscaled: u64, scale: u8) -> Result<u128, Error> {
     if scale as u32 > INTERNAL_SCALE {
@@ -87,7 +116,7 @@ const fn exp_internal(x: u128) -> Result<u128, Error> {
     let mut term = INTERNAL_FACTOR;
     let mut sum = INTERNAL_FACTOR;
     let mut n: u32 = 1;
-    while n <= EXP_TERMS {
+    while n <= EXP_TERMS && term != 0 {
         term = match mul_scaled(term, r) {
             Ok(value) => value,
             Err(err) => return Err(err),
@@ -143,6 +172,9 @@ const fn ln_internal(x: u128) -> Result<u128, Error> {
         };
         let denom = (2 * n + 1) as u128;
         let addend = term / denom;
+        if addend == 0 {
+            break;
+        }
         sum = match sum.checked_add(addend) {
             Some(value) => value,
             None => return Err(Error::Overflow),
@@ -165,8 +197,1129 @@ const fn ln_internal(x: u128) -> Result<u128, Error> {
     Ok(result)
 }
 
+/// Natural logarithm of any positive internal value as a sign and magnitude. Values below one are
+/// doubled into `[1, 2)`, which is exact, and `k * ln(2)` is subtracted afterwards.
+const fn ln_signed_internal(x: u128) -> Result<(bool, u128), Error> {
+    if x == 0 {
+        return Err(Error::InvalidInput(InvalidInputKind::Zero));
+    }
+    if x >= INTERNAL_FACTOR {
+        return match ln_internal(x) {
+            Ok(value) => Ok((false, value)),
+            Err(err) => Err(err),
+        };
+    }
+    let mut value = x;
+    let mut k: u128 = 0;
+    while value < INTERNAL_FACTOR {
+        value *= 2;
+        k += 1;
+    }
+    match ln_internal(value) {
+        // ln(value) < ln(2) <= k * ln(2)
+        Ok(ln) => Ok((true, k * LN2_INTERNAL - ln)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Divides the natural logarithm of `x` by the positive or negative logarithm of a base.
+const fn log_internal(x: u128, negative_base: bool, ln_base: u128) -> Result<(bool, u128), Error> {
+    if ln_base == 0 {
+        return Err(Error::DivisionByZero);
+    }
+    match ln_signed_internal(x) {
+        Ok((negative, ln)) => match div_scaled(ln, ln_base) {
+            Ok(value) => Ok((negative != negative_base, value)),
+            Err(err) => Err(err),
+        },
+        Err(err) => Err(err),
+    }
+}
+
+/// Rounds a signed internal result half away from zero to the scale `S`.
+const fn signed_from_internal<S: ScaleMetrics>(result: Result<(bool, u128), Error>) -> Result<DecimalI64<S>, Error> {
+    let (negative, magnitude) = match result {
+        Ok(value) => value,
+        Err(err) => return Err(err),
+    };
+    match scale_from_internal(magnitude, S::SCALE) {
+        Ok(unscaled) => DecimalI64::from_parts(negative && unscaled != 0, unscaled),
+        Err(err) => Err(err),
+    }
+}
+
+/// Multiplies two values carrying `factor` as their scale, rounding the dropped digits by `mode`.
+const fn mul_rounded(a: u128, b: u128, factor: u128, mode: RoundingMode) -> Result<u128, Error> {
+    let (high, low) = widening_mul(a, b);
+    match wide_div(high, low, factor) {
+        Some((quotient, remainder)) => {
+            if round_up(mode, quotient & 1 == 1, remainder, factor, false) {
+                match quotient.checked_add(1) {
+                    Some(value) => Ok(value),
+                    None => Err(Error::Overflow),
+                }
+            } else {
+                Ok(quotient)
+            }
+        }
+        None => Err(Error::Overflow),
+    }
+}
+
+/// Largest degree accepted by [`DecimalU64::nth_root`], bounded by the width of the
+/// intermediate integers.
+pub const MAX_ROOT_DEGREE: u32 = 16;
+
+/// Little-endian unsigned integer wide enough for `(2 * u64::MAX + 1)^MAX_ROOT_DEGREE` and for the
+/// radicand `u64::MAX * 10^(19 * (MAX_ROOT_DEGREE - 1)) * 2^MAX_ROOT_DEGREE`.
+type BigUint = [u64; 17];
+
+const fn big_from(value: u128) -> BigUint {
+    let mut big = [0; 17];
+    big[0] = value as u64;
+    big[1] = (value >> 64) as u64;
+    big
+}
+
+const fn big_mul(mut value: BigUint, factor: u64) -> Result<BigUint, Error> {
+    let mut carry = 0u128;
+    let mut i = 0;
+    while i < value.len() {
+        let product = value[i] as u128 * factor as u128 + carry;
+        value[i] = product as u64;
+        carry = product >> 64;
+        i += 1;
+    }
+    if carry != 0 {
+        return Err(Error::Overflow);
+    }
+    Ok(value)
+}
+
+const fn big_add(mut value: BigUint, other: &BigUint) -> Result<BigUint, Error> {
+    let mut carry = 0u128;
+    let mut i = 0;
+    while i < value.len() {
+        let sum = value[i] as u128 + other[i] as u128 + carry;
+        value[i] = sum as u64;
+        carry = sum >> 64;
+        i += 1;
+    }
+    if carry != 0 {
+        return Err(Error::Overflow);
+    }
+    Ok(value)
+}
+
+const fn big_div(mut value: BigUint, divisor: u64) -> BigUint {
+    let mut remainder = 0u128;
+    let mut i = value.len();
+    while i > 0 {
+        i -= 1;
+        let current = (remainder << 64) | value[i] as u128;
+        value[i] = (current / divisor as u128) as u64;
+        remainder = current % divisor as u128;
+    }
+    value
+}
+
+/// Compares two big integers, returning -1, 0 or 1.
+const fn big_cmp(a: &BigUint, b: &BigUint) -> i8 {
+    let mut i = a.len();
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return if a[i] < b[i] { -1 } else { 1 };
+        }
+    }
+    0
+}
+
+const fn big_bits(value: &BigUint) -> u32 {
+    let mut i = value.len();
+    while i > 0 {
+        i -= 1;
+        if value[i] != 0 {
+            return i as u32 * 64 + (64 - value[i].leading_zeros());
+        }
+    }
+    0
+}
+
+/// Returns `value^exp`, or an error if it does not fit into [`BigUint`].
+const fn big_pow(value: &BigUint, factor: u64, exp: u32) -> Result<BigUint, Error> {
+    let mut result = *value;
+    let mut i = 0;
+    while i < exp {
+        result = match big_mul(result, factor) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        i += 1;
+    }
+    Ok(result)
+}
+
+/// Computes the `n`-th root of the unscaled `value` at the same scale, i.e. the root of
+/// `value * factor^(n - 1)`, using integer Newton iterations and rounding the result by `mode`.
+const fn root_scaled(value: u64, factor: u64, n: u32, mode: RoundingMode) -> Result<u64, Error> {
+    if n == 0 {
+        return Err(Error::InvalidInput(InvalidInputKind::Zero));
+    }
+    if n > MAX_ROOT_DEGREE {
+        return Err(Error::InvalidInput(InvalidInputKind::DegreeTooLarge));
+    }
+    if n == 1 || value == 0 {
+        return Ok(value);
+    }
+
+    let radicand = match big_pow(&big_from(value as u128), factor, n - 1) {
+        Ok(value) => value,
+        Err(err) => return Err(err),
+    };
+
+    // both the root of any value above one and the root of any value below one are bounded by the
+    // larger of the value and one, and 2^ceil(bits / n) bounds the root of the radicand
+    let mut root = if value > factor { value as u128 } else { factor as u128 };
+    let shift = big_bits(&radicand).div_ceil(n);
+    if shift < 64 && (1u128 << shift) < root {
+        root = 1u128 << shift;
+    }
+
+    // Newton iterations from above decrease monotonically until they reach the floor of the root
+    loop {
+        let mut quotient = radicand;
+        let mut i = 1;
+        while i < n {
+            quotient = big_div(quotient, root as u64);
+            i += 1;
+        }
+        // the root is never below the exact one, so the quotient cannot exceed it
+        let quotient = quotient[0] as u128 | (quotient[1] as u128) << 64;
+        let next = ((n - 1) as u128 * root + quotient) / n as u128;
+        if next >= root {
+            break;
+        }
+        root = next;
+    }
+    let root = root as u64;
+
+    // locate the exact root between `root` and `root + 1`, comparing against the midpoint as
+    // 2^n * radicand against (2 * root + 1)^n, and express it for `round_up` in quarters
+    let power = match big_pow(&big_from(1), root, n) {
+        Ok(value) => value,
+        Err(err) => return Err(err),
+    };
+    let remainder = if big_cmp(&power, &radicand) == 0 {
+        0
+    } else {
+        let scaled = match big_pow(&radicand, 2, n) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        let mut midpoint = big_from(1);
+        let mut i = 0;
+        while i < n {
+            // midpoint * (2 * root + 1) without leaving u64 factors
+            let doubled = match big_mul(midpoint, root) {
+                Ok(value) => match big_mul(value, 2) {
+                    Ok(value) => value,
+                    Err(err) => return Err(err),
+                },
+                Err(err) => return Err(err),
+            };
+            midpoint = match big_add(doubled, &midpoint) {
+                Ok(value) => value,
+                Err(err) => return Err(err),
+            };
+            i += 1;
+        }
+        // the midpoint is odd and the scaled radicand even, so they are never equal
+        if big_cmp(&scaled, &midpoint) > 0 { 3 } else { 1 }
+    };
+    if round_up(mode, root & 1 == 1, remainder, 4, false) {
+        return match root.checked_add(1) {
+            Some(value) => Ok(value),
+            None => Err(Error::Overflow),
+        };
+    }
+    Ok(root)
+}
+
+/// Largest working precision accepted by [`DecimalU64::exp_with`] and [`DecimalU64::ln_with`], chosen so that
+/// `ln` of the largest value still fits into `u128` at that many digits.
+pub const MAX_PRECISION: u32 = 36;
+
+/// `ln(2)` truncated to 38 digits, the most that fit into `u128`.
+const LN2_38: u128 = 69_314_718_055_994_530_941_723_212_145_817_656_807;
+/// Bound on the error of `exp_precise` and `ln_precise` in units of the last working digit. The
+/// analysis in both functions gives less than 256, the bound leaves a factor of two on top.
+const PRECISE_ERROR: u128 = 512;
+
+/// Evaluates `exp` of `unscaled / 10^scale` with `precision` fractional digits as `value * 2^k`,
+/// returning `(value, k)` with `value` in `[1, 2)`.
+const fn exp_precise(unscaled: u64, scale: u8, precision: u32) -> Result<(u128, u32), Error> {
+    // exp(45) exceeds u64::MAX at every scale
+    if unscaled as u128 / POW10_U128[scale as usize] >= 45 {
+        return Err(Error::Overflow);
+    }
+    let factor = POW10_U128[precision as usize];
+    let x = unscaled as u128 * POW10_U128[(precision - scale as u32) as usize];
+    let ln2 = LN2_38 / POW10_U128[(38 - precision) as usize];
+    let k = x / ln2;
+    // ln2 is truncated, so r is at most k <= 64 units above the exact reduced argument, which moves
+    // exp(r) < 2 by at most 128 units
+    let r = x - k * ln2;
+
+    // every term is truncated twice and its error decays with r / n < 1, which keeps the error of
+    // each term below 4 units and that of the at most 32 terms below 128 units
+    let mut term = factor;
+    let mut sum = factor;
+    let mut n: u128 = 1;
+    while term != 0 {
+        term = match mul_rounded(term, r, factor, RoundingMode::Floor) {
+            Ok(value) => value / n,
+            Err(err) => return Err(err),
+        };
+        sum += term;
+        n += 1;
+    }
+    Ok((sum, k as u32))
+}
+
+/// Evaluates `ln` of `unscaled / 10^scale >= 1` with `precision` fractional digits.
+const fn ln_precise(unscaled: u64, scale: u8, precision: u32) -> Result<u128, Error> {
+    let scale_factor = POW10_U128[scale as usize];
+    // k = floor(log2(x)) < 64
+    let mut k: u32 = 0;
+    while scale_factor << (k + 1) <= unscaled as u128 {
+        k += 1;
+    }
+    let factor = POW10_U128[precision as usize];
+    // m = x / 2^k in [1, 2), truncated by at most one unit which moves ln(m) by as much
+    let (high, low) = widening_mul(unscaled as u128, POW10_U128[(precision - scale as u32) as usize]);
+    let m = if k == 0 { low } else { (low >> k) | (high << (128 - k)) };
+
+    // ln(m) = 2 * atanh(z) with z = (m - 1) / (m + 1) < 1/3, the truncation of z is amplified by
+    // less than 2.25 and every term adds at most 3 units, which stays below 128 units after doubling
+    let (high, low) = widening_mul(m - factor, factor);
+    let z = match wide_div(high, low, m + factor) {
+        Some((quotient, _)) => quotient,
+        None => return Err(Error::Overflow),
+    };
+    let z2 = match mul_rounded(z, z, factor, RoundingMode::Floor) {
+        Ok(value) => value,
+        Err(err) => return Err(err),
+    };
+    let mut term = z;
+    let mut sum = z;
+    let mut n: u128 = 1;
+    loop {
+        term = match mul_rounded(term, z2, factor, RoundingMode::Floor) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        let addend = term / (2 * n + 1);
+        if addend == 0 {
+            break;
+        }
+        sum += addend;
+        n += 1;
+    }
+    // the truncated ln2 contributes at most k < 64 units
+    let ln2 = LN2_38 / POW10_U128[(38 - precision) as usize];
+    Ok(2 * sum + k as u128 * ln2)
+}
+
+/// Rounds `value * 2^k` from `precision` fractional digits to `scale` by `mode`.
+const fn round_shifted(value: u128, k: u32, precision: u32, scale: u8, mode: RoundingMode) -> Result<u64, Error> {
+    let (high, low) = if k == 0 {
+        (0, value)
+    } else {
+        (value >> (128 - k), value << k)
+    };
+    let divisor = POW10_U128[(precision - scale as u32) as usize];
+    let (quotient, remainder) = match wide_div(high, low, divisor) {
+        Some(value) => value,
+        None => return Err(Error::Overflow),
+    };
+    let unscaled = if round_up(mode, quotient & 1 == 1, remainder, divisor, false) {
+        quotient + 1
+    } else {
+        quotient
+    };
+    if unscaled > u64::MAX as u128 {
+        return Err(Error::Overflow);
+    }
+    Ok(unscaled as u64)
+}
+
+/// Rounds a working result carrying [`PRECISE_ERROR`] to `scale`. Both ends of the error interval
+/// have to round to the same value for the result to be correctly rounded, otherwise returns
+/// [`Error::Inexact`] as the precision does not suffice to decide.
+const fn round_interval(value: u128, k: u32, precision: u32, scale: u8, mode: RoundingMode) -> Result<u64, Error> {
+    let lower = match round_shifted(value.saturating_sub(PRECISE_ERROR), k, precision, scale, mode) {
+        Ok(value) => value,
+        Err(err) => return Err(err),
+    };
+    match round_shifted(value + PRECISE_ERROR, k, precision, scale, mode) {
+        Ok(upper) if upper == lower => Ok(lower),
+        _ => Err(Error::Inexact),
+    }
+}
+
+/// Computes a correctly rounded `exp` at the given working precision.
+const fn exp_correctly_rounded(unscaled: u64, scale: u8, precision: u32, mode: RoundingMode) -> Result<u64, Error> {
+    if precision > MAX_PRECISION {
+        return Err(Error::Overflow);
+    }
+    // the error bound spans several units at the scale without extra digits
+    if precision <= scale as u32 {
+        return Err(Error::Inexact);
+    }
+    // exp(0) is the only exact result, every other one is transcendental and never sits on a boundary
+    let scale_factor = POW10_U128[scale as usize];
+    if unscaled == 0 {
+        return Ok(scale_factor as u64);
+    }
+    // for 2 * x^2 < 10^-scale, exp(x) - 1 - x < x^2 / (2 - 2x / 3) stays below half a unit, so exp(x) lies
+    // strictly between 1 + x and the midpoint above it, closer to 1 + x than any error bound can resolve
+    if (unscaled as u128) * (unscaled as u128) < scale_factor / 2 {
+        let truncated = scale_factor as u64 + unscaled;
+        // any remainder between zero and half a unit rounds the same way
+        return Ok(if round_up(mode, truncated & 1 == 1, 1, 4, false) {
+            truncated + 1
+        } else {
+            truncated
+        });
+    }
+    match exp_precise(unscaled, scale, precision) {
+        Ok((value, k)) => round_interval(value, k, precision, scale, mode),
+        Err(err) => Err(err),
+    }
+}
+
+/// Computes a correctly rounded `ln` at the given working precision.
+const fn ln_correctly_rounded(unscaled: u64, scale: u8, precision: u32, mode: RoundingMode) -> Result<u64, Error> {
+    if precision > MAX_PRECISION {
+        return Err(Error::Overflow);
+    }
+    // the error bound spans several units at the scale without extra digits
+    if precision <= scale as u32 {
+        return Err(Error::Inexact);
+    }
+    let scale_factor = POW10_U128[scale as usize];
+    if (unscaled as u128) < scale_factor {
+        return Err(Error::InvalidInput(InvalidInputKind::LessThanOne));
+    }
+    // ln(1) is the only exact result
+    if unscaled as u128 == scale_factor {
+        return Ok(0);
+    }
+    // for x^2 < 10^-scale, x - x^2 / 2 < ln(1 + x) < x puts ln(1 + x) strictly between the midpoint below
+    // x and x itself, closer to x than any error bound can resolve
+    let x = unscaled as u128 - scale_factor;
+    if x * x < scale_factor {
+        let truncated = (x - 1) as u64;
+        // any remainder between half a unit and a full unit rounds the same way
+        return Ok(if round_up(mode, truncated & 1 == 1, 3, 4, false) {
+            truncated + 1
+        } else {
+            truncated
+        });
+    }
+    match ln_precise(unscaled, scale, precision) {
+        Ok(value) => round_interval(value, 0, precision, scale, mode),
+        Err(err) => Err(err),
+    }
+}
+
+/// Working precision of the first attempt of [`DecimalU64::exp_rounded`] and [`DecimalU64::ln_rounded`].
+/// Eight extra digits decide the rounding for all but about one in `10^5` arguments.
+const fn first_precision(scale: u8) -> u32 {
+    scale as u32 + 8
+}
+
+/// `floor((2^128 - 1) / 10^scale)` for every scale, converts an unscaled value to a binary fixed
+/// point number with 64 fractional bits using one wide multiplication instead of a division.
+const RECIPROCALS: [u128; 20] = {
+    let mut table = [0u128; 20];
+    let mut scale = 0;
+    while scale < 20 {
+        table[scale] = u128::MAX / POW10_U128[scale];
+        scale += 1;
+    }
+    table
+};
+
+/// `ln(2)` with 96 fractional bits.
+const LN2_Q96: u128 = 54_916_777_467_707_473_351_141_471_128;
+/// `1 / ln(2)` with 63 fractional bits.
+const INV_LN2_Q63: u64 = 13_306_513_097_844_322_492;
+
+/// `exp(j / 64)` with 63 fractional bits for every `j / 64` below `ln(2)`.
+const EXP_TABLE: [u64; 45] = [
+    9_223_372_036_854_775_808,
+    9_368_619_011_877_750_973,
+    9_516_153_293_936_485_575,
+    9_666_010_902_875_627_029,
+    9_818_228_425_769_839_595,
+    9_972_843_025_856_377_387,
+    10_129_892_451_608_324_933,
+    10_289_415_045_950_720_489,
+    10_451_449_755_621_812_174,
+    10_616_036_140_681_732_443,
+    10_783_214_384_170_912_385,
+    10_953_025_301_920_593_926,
+    11_125_510_352_517_835_114,
+    11_300_711_647_427_441_404,
+    11_478_671_961_273_294_162,
+    11_659_434_742_281_586_546,
+    11_843_044_122_888_516_411,
+    12_029_544_930_515_026_078,
+    12_218_982_698_511_219_557,
+    12_411_403_677_273_129_275,
+    12_606_854_845_534_546_395,
+    12_805_383_921_836_671_593,
+    13_007_039_376_178_386_559,
+    13_211_870_441_849_990_565,
+    13_419_927_127_453_291_282,
+    13_631_260_229_110_984_474,
+    13_845_921_342_868_303_453,
+    14_063_962_877_289_966_091,
+    14_285_438_066_255_494_881,
+    14_510_400_981_956_033_978,
+    14_738_906_548_095_836_316,
+    14_971_010_553_301_643_907,
+    15_206_769_664_743_235_148,
+    15_446_241_441_968_464_537,
+    15_689_484_350_956_172_567,
+    15_936_557_778_390_396_747,
+    16_187_522_046_159_368_738,
+    16_442_438_426_082_837_469,
+    16_701_369_154_871_313_855,
+    16_964_377_449_320_889_330,
+    17_231_527_521_747_337_957,
+    17_502_884_595_663_270_289,
+    17_778_514_921_702_166_468,
+    18_058_485_793_793_176_364,
+    18_342_865_565_590_635_753,
+];
+
+/// `exp(j / 4096)` with 63 fractional bits, refines an entry of [`EXP_TABLE`] by the next six bits.
+const EXP_FINE_TABLE: [u64; 64] = [
+    9_223_372_036_854_775_808,
+    9_225_624_111_568_738_987,
+    9_227_876_736_172_752_898,
+    9_230_129_910_801_084_432,
+    9_232_383_635_588_033_263,
+    9_234_637_910_667_931_856,
+    9_236_892_736_175_145_479,
+    9_239_148_112_244_072_203,
+    9_241_404_039_009_142_921,
+    9_243_660_516_604_821_346,
+    9_245_917_545_165_604_024,
+    9_248_175_124_826_020_342,
+    9_250_433_255_720_632_533,
+    9_252_691_937_984_035_689,
+    9_254_951_171_750_857_763,
+    9_257_210_957_155_759_584,
+    9_259_471_294_333_434_857,
+    9_261_732_183_418_610_179,
+    9_263_993_624_546_045_040,
+    9_266_255_617_850_531_836,
+    9_268_518_163_466_895_875,
+    9_270_781_261_529_995_386,
+    9_273_044_912_174_721_526,
+    9_275_309_115_535_998_387,
+    9_277_573_871_748_783_008,
+    9_279_839_180_948_065_379,
+    9_282_105_043_268_868_450,
+    9_284_371_458_846_248_142,
+    9_286_638_427_815_293_349,
+    9_288_905_950_311_125_953,
+    9_291_174_026_468_900_828,
+    9_293_442_656_423_805_847,
+    9_295_711_840_311_061_894,
+    9_297_981_578_265_922_870,
+    9_300_251_870_423_675_698,
+    9_302_522_716_919_640_339,
+    9_304_794_117_889_169_791,
+    9_307_066_073_467_650_102,
+    9_309_338_583_790_500_379,
+    9_311_611_648_993_172_793,
+    9_313_885_269_211_152_588,
+    9_316_159_444_579_958_091,
+    9_318_434_175_235_140_717,
+    9_320_709_461_312_284_979,
+    9_322_985_302_947_008_497,
+    9_325_261_700_274_962_004,
+    9_327_538_653_431_829_354,
+    9_329_816_162_553_327_532,
+    9_332_094_227_775_206_660,
+    9_334_372_849_233_250_009,
+    9_336_652_027_063_274_001,
+    9_338_931_761_401_128_221,
+    9_341_212_052_382_695_427,
+    9_343_492_900_143_891_552,
+    9_345_774_304_820_665_717,
+    9_348_056_266_549_000_239,
+    9_350_338_785_464_910_637,
+    9_352_621_861_704_445_640,
+    9_354_905_495_403_687_198,
+    9_357_189_686_698_750_486,
+    9_359_474_435_725_783_916,
+    9_361_759_742_620_969_143,
+    9_364_045_607_520_521_072,
+    9_366_332_030_560_687_871,
+];
+
+/// `ln(1 + j / 64)` with 64 fractional bits.
+const LN_TABLE: [u64; 64] = [
+    0,
+    286_001_761_100_004_474,
+    567_636_912_149_151_763,
+    845_036_787_304_660_515,
+    1_118_326_883_213_202_225,
+    1_387_627_199_918_624_915,
+    1_653_052_557_241_306_012,
+    1_914_712_888_716_911_461,
+    2_172_713_514_977_912_298,
+    2_427_155_398_279_344_845,
+    2_678_135_379_708_233_368,
+    2_925_746_400_471_432_400,
+    3_170_077_708_527_314_833,
+    3_411_215_051_710_927_416,
+    3_649_240_858_398_386_413,
+    3_884_234_406_663_022_002,
+    4_116_271_982_791_902_040,
+    4_345_427_029_955_824_596,
+    4_571_770_287_757_743_387,
+    4_795_369_923_323_071_870,
+    5_016_291_654_539_687_950,
+    5_234_598_866_005_104_265,
+    5_450_352_718_192_624_241,
+    5_663_612_250_306_881_309,
+    5_874_434_477_261_523_444,
+    6_082_874_481_177_572_391,
+    6_288_985_497_769_814_338,
+    6_492_818_997_960_162_065,
+    6_694_424_765_030_996_596,
+    6_893_850_967_607_807_379,
+    7_091_144_228_738_788_055,
+    7_286_349_691_319_216_873,
+    7_479_511_080_090_283_979,
+    7_670_670_760_425_367_093,
+    7_859_869_794_101_463_603,
+    8_047_147_992_239_435_742,
+    8_232_543_965_583_804_081,
+    8_416_095_172_280_931_719,
+    8_597_837_963_303_486_204,
+    8_777_807_625_658_965_902,
+    8_956_038_423_510_758_093,
+    9_132_563_637_331_589_991,
+    9_307_415_601_201_280_565,
+    9_480_625_738_353_345_139,
+    9_652_224_595_068_196_277,
+    9_822_241_873_004_378_095,
+    9_990_706_460_053_425_484,
+    10_157_646_459_798_517_347,
+    10_323_089_219_652_059_631,
+    10_487_061_357_742_658_583,
+    10_649_588_788_617_598_812,
+    10_810_696_747_822_898_636,
+    10_970_409_815_419_252_990,
+    11_128_751_938_488_670_391,
+    11_285_746_452_683_345_230,
+    11_441_416_102_865_261_856,
+    11_595_783_062_882_186_019,
+    11_748_868_954_523_046_888,
+    11_900_694_865_693_235_217,
+    12_051_281_367_848_027_366,
+    12_200_648_532_720_179_060,
+    12_348_815_948_375_706_121,
+    12_495_802_734_629_971_929,
+    12_641_627_557_854_423_943,
+];
+
+/// `1 / (1 + j / 64)` with 63 fractional bits.
+const INV_TABLE: [u64; 64] = [
+    9_223_372_036_854_775_808,
+    9_081_474_005_518_548_488,
+    8_943_875_914_525_843_208,
+    8_810_385_229_234_412_712,
+    8_680_820_740_569_200_760,
+    8_555_011_744_329_067_416,
+    8_432_797_290_838_652_167,
+    8_314_025_498_009_938_757,
+    8_198_552_921_648_689_607,
+    8_086_243_977_516_515_777,
+    7_976_970_410_252_779_077,
+    7_870_610_804_782_742_023,
+    7_767_050_136_298_758_575,
+    7_666_179_355_307_865_607,
+    7_567_895_004_598_790_407,
+    7_472_098_865_300_071_541,
+    7_378_697_629_483_820_646,
+    7_287_602_597_021_057_429,
+    7_198_729_394_618_361_606,
+    7_111_997_715_165_128_334,
+    7_027_331_075_698_876_806,
+    6_944_656_592_455_360_608,
+    6_863_904_771_612_856_415,
+    6_785_009_314_467_881_054,
+    6_707_906_935_894_382_406,
+    6_632_537_195_041_636_536,
+    6_558_842_337_318_951_686,
+    6_486_767_146_798_963_206,
+    6_416_258_808_246_800_562,
+    6_347_266_778_050_598_406,
+    6_279_742_663_390_485_657,
+    6_213_640_109_039_006_860,
+    6_148_914_691_236_517_205,
+    6_085_523_818_130_986_100,
+    6_023_426_636_313_322_977,
+    5_962_583_943_017_228_805,
+    5_902_958_103_587_056_517,
+    5_844_512_973_848_570_809,
+    5_787_213_827_046_133_840,
+    5_731_027_285_035_977_201,
+    5_675_921_253_449_092_805,
+    5_621_864_860_559_101_445,
+    5_568_828_399_610_430_677,
+    5_516_783_274_380_426_652,
+    5_465_701_947_765_793_071,
+    5_415_557_893_199_134_419,
+    5_366_325_548_715_505_925,
+    5_317_980_273_501_852_718,
+    5_270_498_306_774_157_605,
+    5_223_856_728_838_103_112,
+    5_178_033_424_199_172_383,
+    5_133_007_046_597_440_450,
+    5_088_756_985_850_910_791,
+    5_045_263_336_399_193_604,
+    5_002_506_867_446_658_065,
+    4_960_468_994_610_971_863,
+    4_919_131_752_989_213_764,
+    4_878_477_771_559_550_841,
+    4_838_490_248_841_849_604,
+    4_799_152_929_745_574_404,
+    4_760_450_083_537_948_804,
+    4_722_366_482_869_645_214,
+    4_684_887_383_799_251_204,
+    4_647_998_506_761_461_825,
+];
+
+/// `ln(1 + j / 4096)` with 64 fractional bits, refines an entry of [`LN_TABLE`] by the next six bits.
+const LN_FINE_TABLE: [u64; 64] = [
+    0,
+    4_503_049_961_018_713,
+    9_005_000_947_051_281,
+    13_505_853_494_379_273,
+    18_005_608_138_891_807,
+    22_504_265_416_085_937,
+    27_001_825_861_067_029,
+    31_498_290_008_549_151,
+    35_993_658_392_855_450,
+    40_487_931_547_918_531,
+    44_981_110_007_280_844,
+    49_473_194_304_095_055,
+    53_964_184_971_124_435,
+    58_454_082_540_743_233,
+    62_942_887_544_937_056,
+    67_430_600_515_303_247,
+    71_917_221_983_051_266,
+    76_402_752_479_003_062,
+    80_887_192_533_593_453,
+    85_370_542_676_870_503,
+    89_852_803_438_495_894,
+    94_333_975_347_745_306,
+    98_814_058_933_508_791,
+    103_293_054_724_291_144,
+    107_770_963_248_212_282,
+    112_247_785_033_007_615,
+    116_723_520_606_028_417,
+    121_198_170_494_242_204,
+    125_671_735_224_233_104,
+    130_144_215_322_202_224,
+    134_615_611_313_968_031,
+    139_085_923_724_966_714,
+    143_555_153_080_252_560,
+    148_023_299_904_498_320,
+    152_490_364_721_995_582,
+    156_956_348_056_655_140,
+    161_421_250_432_007_358,
+    165_885_072_371_202_545,
+    170_347_814_397_011_318,
+    174_809_477_031_824_970,
+    179_270_060_797_655_839,
+    183_729_566_216_137_673,
+    188_187_993_808_525_995,
+    192_645_344_095_698_472,
+    197_101_617_598_155_274,
+    201_556_814_836_019_446,
+    206_010_936_329_037_268,
+    210_463_982_596_578_619,
+    214_915_954_157_637_340,
+    219_366_851_530_831_599,
+    223_816_675_234_404_253,
+    228_265_425_786_223_208,
+    232_713_103_703_781_782,
+    237_159_709_504_199_068,
+    241_605_243_704_220_291,
+    246_049_706_820_217_170,
+    250_493_099_368_188_280,
+    254_935_421_863_759_408,
+    259_376_674_822_183_915,
+    263_816_858_758_343_091,
+    268_255_974_186_746_517,
+    272_694_021_621_532_420,
+    277_131_001_576_468_033,
+    281_566_914_564_949_947,
+];
+
+/// `1 / (1 + j / 4096)` with 63 fractional bits.
+const INV_FINE_TABLE: [u64; 64] = [
+    9_223_372_036_854_775_808,
+    9_221_120_786_662_719_480,
+    9_218_870_635_177_443_072,
+    9_216_621_581_594_818_665,
+    9_214_373_625_111_502_856,
+    9_212_126_764_924_935_798,
+    9_209_881_000_233_340_251,
+    9_207_636_330_235_720_621,
+    9_205_392_754_131_862_015,
+    9_203_150_271_122_329_284,
+    9_200_908_880_408_466_076,
+    9_198_668_581_192_393_891,
+    9_196_429_372_677_011_127,
+    9_194_191_254_065_992_142,
+    9_191_954_224_563_786_304,
+    9_189_718_283_375_617_054,
+    9_187_483_429_707_480_960,
+    9_185_249_662_766_146_781,
+    9_183_016_981_759_154_523,
+    9_180_785_385_894_814_510,
+    9_178_554_874_382_206_441,
+    9_176_325_446_431_178_458,
+    9_174_097_101_252_346_214,
+    9_171_869_838_057_091_942,
+    9_169_643_656_057_563_522,
+    9_167_418_554_466_673_552,
+    9_165_194_532_498_098_425,
+    9_162_971_589_366_277_397,
+    9_160_749_724_286_411_666,
+    9_158_528_936_474_463_445,
+    9_156_309_225_147_155_044,
+    9_154_090_589_521_967_945,
+    9_151_873_028_817_141_887,
+    9_149_656_542_251_673_943,
+    9_147_441_129_045_317_605,
+    9_145_226_788_418_581_871,
+    9_143_013_519_592_730_327,
+    9_140_801_321_789_780_235,
+    9_138_590_194_232_501_623,
+    9_136_380_136_144_416_375,
+    9_134_171_146_749_797_319,
+    9_131_963_225_273_667_322,
+    9_129_756_370_941_798_383,
+    9_127_550_582_980_710_730,
+    9_125_345_860_617_671_911,
+    9_123_142_203_080_695_897,
+    9_120_939_609_598_542_180,
+    9_118_738_079_400_714_871,
+    9_116_537_611_717_461_803,
+    9_114_338_205_779_773_633,
+    9_112_139_860_819_382_950,
+    9_109_942_576_068_763_373,
+    9_107_746_350_761_128_667,
+    9_105_551_184_130_431_841,
+    9_103_357_075_411_364_267,
+    9_101_164_023_839_354_784,
+    9_098_972_028_650_568_813,
+    9_096_781_089_081_907_467,
+    9_094_591_204_371_006_671,
+    9_092_402_373_756_236_272,
+    9_090_214_596_476_699_160,
+    9_088_027_871_772_230_385,
+    9_085_842_198_883_396_275,
+    9_083_657_577_051_493_558,
+];
+
+/// Converts `unscaled / 10^scale` to a binary fixed point number with 64 fractional bits, within one unit
+/// of the exact value.
+#[inline]
+const fn to_q64(unscaled: u64, scale: u8) -> u128 {
+    let reciprocal = RECIPROCALS[scale as usize];
+    let high = unscaled as u128 * (reciprocal >> 64);
+    let low = (unscaled as u128 * (reciprocal as u64 as u128)) >> 64;
+    // both the reciprocal and the product are truncated, which leaves the sum up to two units low
+    high + low + 1
+}
+
+/// Converts a binary fixed point number with 64 fractional bits to `scale`, rounding half-up.
+#[inline]
+const fn from_q64(value: u128, scale: u8) -> Result<u64, Error> {
+    let factor = POW10_U128[scale as usize];
+    let fraction = ((value as u64 as u128) * factor + (1 << 63)) >> 64;
+    let unscaled = (value >> 64) * factor + fraction;
+    if unscaled > u64::MAX as u128 {
+        return Err(Error::Overflow);
+    }
+    Ok(unscaled as u64)
+}
+
+/// Table driven `exp` of `unscaled / 10^scale`. Splits the argument into `k * ln(2) + j / 64 + i / 4096 + s`
+/// and combines `2^k`, two table entries and a polynomial in `s < 2^-12`, all in binary fixed point.
+#[inline]
+const fn exp_table(unscaled: u64, scale: u8) -> Result<u64, Error> {
+    let x = to_q64(unscaled, scale);
+    // exp(45) exceeds u64::MAX at every scale
+    if x >> 64 >= 45 {
+        return Err(Error::Overflow);
+    }
+    // estimate k = floor(x / ln(2)) with a multiplication and correct it by at most one either way
+    let mut k = (((x >> 32) as u64 as u128 * INV_LN2_Q63 as u128) >> 95) as u32;
+    let x = x << 32;
+    if (k as u128) * LN2_Q96 > x {
+        k -= 1;
+    }
+    let mut r = x - (k as u128) * LN2_Q96;
+    if r >= LN2_Q96 {
+        k += 1;
+        r -= LN2_Q96;
+    }
+    // all remaining factors fit into u64, which keeps every product a single widening multiplication
+    let r = (r >> 32) as u64;
+    let j = (r >> 58) as usize;
+    let fine = ((r >> 52) & 63) as usize;
+    let s = r & ((1 << 52) - 1);
+
+    // exp(s) to degree 4, the first omitted term is below 2^-64
+    const ONE: u64 = 1 << 63;
+    let mut poly = ONE / 24;
+    poly = ONE / 6 + ((poly as u128 * s as u128) >> 64) as u64;
+    poly = ONE / 2 + ((poly as u128 * s as u128) >> 64) as u64;
+    poly = ONE + ((poly as u128 * s as u128) >> 64) as u64;
+    poly = ONE + ((poly as u128 * s as u128) >> 64) as u64;
+
+    // the table product does not depend on the polynomial and overlaps with it, exp(r) < 2 keeps
+    // both products below 2^64 with 63 fractional bits
+    let table = ((EXP_TABLE[j] as u128 * EXP_FINE_TABLE[fine] as u128 + (1 << 62)) >> 63) as u64;
+    let exp_r = ((table as u128 * poly as u128 + (1 << 62)) >> 63) as u64;
+    let value = exp_r as u128 * POW10_U128[scale as usize] as u64 as u128;
+    let unscaled = if k < 63 {
+        (value + (1 << (62 - k))) >> (63 - k)
+    } else if k - 63 < value.leading_zeros() {
+        value << (k - 63)
+    } else {
+        return Err(Error::Overflow);
+    };
+    if unscaled > u64::MAX as u128 {
+        return Err(Error::Overflow);
+    }
+    Ok(unscaled as u64)
+}
+
+/// Table driven `ln` of `unscaled / 10^scale >= 1`. Splits the argument into
+/// `2^k * (1 + j / 64) * (1 + i / 4096) * (1 + t)` and sums `k * ln(2)`, two table entries and a polynomial
+/// in `t < 2^-12`, all in binary fixed point.
+#[inline]
+const fn ln_table(unscaled: u64, scale: u8) -> Result<u64, Error> {
+    if (unscaled as u128) < POW10_U128[scale as usize] {
+        return Err(Error::InvalidInput(InvalidInputKind::LessThanOne));
+    }
+    let x = to_q64(unscaled, scale);
+    // x may come out a unit off one
+    if x >> 64 == 0 {
+        return Ok(0);
+    }
+    // x = 2^k * m with m in [1, 2) and 64 fractional bits
+    let k = 63 - x.leading_zeros();
+    let m = (x >> (k + 1)) as u64;
+    // m = (1 + j / 64) * (1 + t1) with t1 < 2^-6 and 64 fractional bits
+    let j = ((m >> 57) & 63) as usize;
+    let t1 = (((m & ((1 << 57) - 1)) as u128 * INV_TABLE[j] as u128) >> 62) as u64;
+    // 1 + t1 = (1 + fine / 4096) * (1 + t) with t < 2^-12, the mask is redundant but spares a bounds
+    // check that costs more than the rest of the reduction
+    let fine = ((t1 >> 52) & 63) as usize;
+    let t = (((t1 & ((1 << 52) - 1)) as u128 * INV_FINE_TABLE[fine] as u128) >> 63) as u64;
+
+    // ln(1 + t) = t * (1 - t * (1/2 - t * (1/3 - ...))) to degree 5, every bracket stays positive and the
+    // first omitted term is below 2^-64
+    const ONE: u64 = 1 << 63;
+    let mut poly = ONE / 5;
+    poly = ONE / 4 - ((poly as u128 * t as u128) >> 64) as u64;
+    poly = ONE / 3 - ((poly as u128 * t as u128) >> 64) as u64;
+    poly = ONE / 2 - ((poly as u128 * t as u128) >> 64) as u64;
+    poly = ONE - ((poly as u128 * t as u128) >> 64) as u64;
+    let ln = ((k as u128 * LN2_Q96) >> 32)
+        + LN_TABLE[j] as u128
+        + LN_FINE_TABLE[fine] as u128
+        + ((poly as u128 * t as u128) >> 63);
+    from_q64(ln, scale)
+}
+
 impl<S: ScaleMetrics> DecimalU64<S> {
-    /// Computes the natural logarithm, returning an error for values less than one.
+    /// Raises this decimal to an integer power, truncating the digits dropped by every multiplication
+    /// like `Mul`. Panics on overflow.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U4};
+    ///
+    /// let rate = DecimalU64::<U4>::from_str("1.5").unwrap();
+    /// assert_eq!("3.3750", rate.pow(3).to_string());
+    /// ```
+    pub const fn pow(self, exp: u32) -> Self {
+        match self.checked_pow::<Floor>(exp) {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to multiply with overflow"),
+        }
+    }
+
+    /// Raises this decimal to an integer power using exponentiation by squaring. Intermediates are kept in
+    /// 128 bits at the value's scale and the digits dropped by every multiplication are resolved by `R`.
+    /// Returns an error if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::HalfUp;
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// // ten years of 5% compounding
+    /// let growth = DecimalU64::<U8>::from_str("1.05").unwrap();
+    /// assert_eq!("1.62889462", growth.checked_pow::<HalfUp>(10).unwrap().to_string());
+    /// ```
+    pub const fn checked_pow<R: RoundingPolicy>(self, exp: u32) -> Result<Self, Error> {
+        let factor = S::SCALE_FACTOR as u128;
+        let mut result = factor;
+        let mut base = self.0 as u128;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = match mul_rounded(result, base, factor, R::MODE) {
+                    Ok(value) => value,
+                    Err(err) => return Err(err),
+                };
+            }
+            exp >>= 1;
+            // the last square is never used, and any earlier one does not exceed the final result
+            if exp > 0 {
+                base = match mul_rounded(base, base, factor, R::MODE) {
+                    Ok(value) => value,
+                    Err(err) => return Err(err),
+                };
+            }
+        }
+        if result > u64::MAX as u128 {
+            return Err(Error::Overflow);
+        }
+        Ok(DecimalU64::new(result as u64))
+    }
+
+    /// Raises this decimal to a fractional power as `exp(exponent * ln(self))`, evaluated at the
+    /// internal scale and rounded half-up at the end. Bases below one are inverted first, so results
+    /// too small for the scale come out as zero. Returns an error on overflow.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U6};
+    ///
+    /// let variance = DecimalU64::<U6>::from_str("0.0625").unwrap();
+    /// let half = DecimalU64::<U6>::from_str("0.5").unwrap();
+    /// assert_eq!("0.250000", variance.powd(half).unwrap().to_string());
+    /// ```
+    pub const fn powd(self, exponent: Self) -> Result<Self, Error> {
+        if exponent.0 == 0 {
+            return Ok(Self::ONE);
+        }
+        if self.0 == 0 {
+            return Ok(Self::ZERO);
+        }
+        let value = match scale_to_internal(self.0, S::SCALE) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        let exponent = match scale_to_internal(exponent.0, S::SCALE) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        let invert = value < INTERNAL_FACTOR;
+        let base = if invert {
+            match div_scaled(INTERNAL_FACTOR, value) {
+                Ok(value) => value,
+                Err(err) => return Err(err),
+            }
+        } else {
+            value
+        };
+        let ln = match ln_internal(base) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        let power = match mul_scaled(ln, exponent) {
+            Ok(value) => exp_internal(value),
+            Err(err) => Err(err),
+        };
+        let result = match (power, invert) {
+            (Ok(power), false) => power,
+            (Ok(power), true) => match div_scaled(INTERNAL_FACTOR, power) {
+                Ok(value) => value,
+                Err(err) => return Err(err),
+            },
+            // the reciprocal of an overflowing power is below the smallest representable value
+            (Err(Error::Overflow), true) => return Ok(Self::ZERO),
+            (Err(err), _) => return Err(err),
+        };
+        let unscaled = match scale_from_internal(result, S::SCALE) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        Ok(DecimalU64::new(unscaled))
+    }
+
+    /// Computes the square root with integer Newton iterations on the scaled value, rounding the
+    /// digits beyond the scale by `R`. The result is correctly rounded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::HalfUp;
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// let variance = DecimalU64::<U8>::from_str("2").unwrap();
+    /// assert_eq!("1.41421356", variance.sqrt::<HalfUp>().to_string());
+    /// ```
+    pub const fn sqrt<R: RoundingPolicy>(self) -> Self {
+        match root_scaled(self.0, S::SCALE_FACTOR, 2, R::MODE) {
+            Ok(unscaled) => DecimalU64::new(unscaled),
+            // the square root of a u64 scaled by at most 10^19 always fits
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Computes the `n`-th root with integer Newton iterations on the scaled value, rounding the digits
+    /// beyond the scale by `R`. The result is correctly rounded. The degree must be between 1 and
+    /// [`MAX_ROOT_DEGREE`], otherwise [`Error::InvalidInput`] is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::HalfUp;
+    /// use decimal64::{DecimalU64, U6};
+    ///
+    /// // monthly rate equivalent to 12% a year
+    /// let growth = DecimalU64::<U6>::from_str("1.12").unwrap();
+    /// assert_eq!("1.009489", growth.nth_root::<HalfUp>(12).unwrap().to_string());
+    /// ```
+    pub const fn nth_root<R: RoundingPolicy>(self, n: u32) -> Result<Self, Error> {
+        match root_scaled(self.0, S::SCALE_FACTOR, n, R::MODE) {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Computes the natural logarithm, returning an error for values less than one. Use
+    /// [`Self::ln_signed`] for those and [`Self::ln_fast`] on latency sensitive paths.
     pub const fn ln(self) -> Result<Self, Error> {
         let value = match scale_to_internal(self.0, S::SCALE) {
             Ok(value) => value,
@@ -183,7 +1336,73 @@ impl<S: ScaleMetrics> DecimalU64<S> {
         Ok(DecimalU64::new(unscaled))
     }
 
-    /// Computes the natural exponential, returning an error on overflow.
+    /// Computes the natural logarithm of any positive value, returning a negative result for values
+    /// below one. Returns an error for zero or if the result does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// // log-return of a price falling from 100 to 95
+    /// let ratio = DecimalU64::<U8>::from_str("0.95").unwrap();
+    /// assert_eq!("-0.05129329", ratio.ln_signed().unwrap().to_string());
+    /// ```
+    pub const fn ln_signed(self) -> Result<DecimalI64<S>, Error> {
+        let value = match scale_to_internal(self.0, S::SCALE) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        signed_from_internal(ln_signed_internal(value))
+    }
+
+    /// Computes the base 10 logarithm of any positive value. Returns an error for zero or if the result
+    /// does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U6};
+    ///
+    /// assert_eq!("3.000000", DecimalU64::<U6>::from_str("1000").unwrap().log10().unwrap().to_string());
+    /// assert_eq!("-2.000000", DecimalU64::<U6>::from_str("0.01").unwrap().log10().unwrap().to_string());
+    /// ```
+    pub const fn log10(self) -> Result<DecimalI64<S>, Error> {
+        let value = match scale_to_internal(self.0, S::SCALE) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        signed_from_internal(log_internal(value, false, LN10_INTERNAL))
+    }
+
+    /// Computes the base 2 logarithm of any positive value. Powers of two come out exact. Returns an
+    /// error for zero or if the result does not fit.
+    pub const fn log2(self) -> Result<DecimalI64<S>, Error> {
+        let value = match scale_to_internal(self.0, S::SCALE) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        signed_from_internal(log_internal(value, false, LN2_INTERNAL))
+    }
+
+    /// Computes the logarithm to an arbitrary positive `base` as `ln(self) / ln(base)`. Returns
+    /// [`Error::DivisionByZero`] for a base of one and an error for zero or if the result does not fit.
+    /// Bases close to one amplify the error of the numerator.
+    pub const fn log_base(self, base: Self) -> Result<DecimalI64<S>, Error> {
+        let value = match scale_to_internal(self.0, S::SCALE) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        let base = match scale_to_internal(base.0, S::SCALE) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+        match ln_signed_internal(base) {
+            Ok((negative, ln_base)) => signed_from_internal(log_internal(value, negative, ln_base)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Computes the natural exponential, returning an error on overflow. Use [`Self::exp_fast`] on latency
+    /// sensitive paths.
     pub const fn exp(self) -> Result<Self, Error> {
         let value = match scale_to_internal(self.0, S::SCALE) {
             Ok(value) => value,
@@ -199,12 +1418,123 @@ impl<S: ScaleMetrics> DecimalU64<S> {
         };
         Ok(DecimalU64::new(unscaled))
     }
+
+    /// Computes the natural exponential with `precision` fractional digits of working precision and
+    /// rounds the result to the scale by `R`. The result is only returned if the error bound of the
+    /// working precision guarantees it is correctly rounded, otherwise [`Error::Inexact`] asks for a
+    /// higher precision. Returns [`Error::Overflow`] if the result does not fit or `precision` exceeds
+    /// [`MAX_PRECISION`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::{Floor, HalfUp};
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// // e = 2.718281828459...
+    /// assert_eq!("2.71828183", DecimalU64::<U8>::ONE.exp_with::<HalfUp>(20).unwrap().to_string());
+    /// assert_eq!("2.71828182", DecimalU64::<U8>::ONE.exp_with::<Floor>(20).unwrap().to_string());
+    /// ```
+    pub const fn exp_with<R: RoundingPolicy>(self, precision: u32) -> Result<Self, Error> {
+        match exp_correctly_rounded(self.0, S::SCALE, precision, R::MODE) {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Computes the natural exponential correctly rounded by `R`. Evaluates with eight digits beyond the
+    /// scale first and retries at [`MAX_PRECISION`] if those do not decide the rounding. Returns
+    /// [`Error::Inexact`] if even that does not suffice, which takes an exact result within a relative
+    /// `10^-33` of a rounding boundary, and [`Error::Overflow`] if the result does not fit.
+    pub const fn exp_rounded<R: RoundingPolicy>(self) -> Result<Self, Error> {
+        let unscaled = match exp_correctly_rounded(self.0, S::SCALE, first_precision(S::SCALE), R::MODE) {
+            Err(Error::Inexact) => exp_correctly_rounded(self.0, S::SCALE, MAX_PRECISION, R::MODE),
+            result => result,
+        };
+        match unscaled {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Computes the natural exponential from precomputed tables in binary fixed point, without any
+    /// division. Trades the last digits at high scales for latency, see the [module](crate::math) documentation
+    /// for the error bound. Returns an error on overflow.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// assert_eq!("2.71828183", DecimalU64::<U8>::ONE.exp_fast().unwrap().to_string());
+    /// ```
+    #[inline]
+    pub const fn exp_fast(self) -> Result<Self, Error> {
+        match exp_table(self.0, S::SCALE) {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Computes the natural logarithm with `precision` fractional digits of working precision and
+    /// rounds the result to the scale by `R`. The result is only returned if the error bound of the
+    /// working precision guarantees it is correctly rounded, otherwise [`Error::Inexact`] asks for a
+    /// higher precision. Returns an error for values less than one and [`Error::Overflow`] if
+    /// `precision` exceeds [`MAX_PRECISION`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::{Ceil, HalfUp};
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// // ln(2) = 0.693147180559...
+    /// assert_eq!("0.69314718", DecimalU64::<U8>::TWO.ln_with::<HalfUp>(20).unwrap().to_string());
+    /// assert_eq!("0.69314719", DecimalU64::<U8>::TWO.ln_with::<Ceil>(20).unwrap().to_string());
+    /// ```
+    pub const fn ln_with<R: RoundingPolicy>(self, precision: u32) -> Result<Self, Error> {
+        match ln_correctly_rounded(self.0, S::SCALE, precision, R::MODE) {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Computes the natural logarithm correctly rounded by `R`. Evaluates with eight digits beyond the
+    /// scale first and retries at [`MAX_PRECISION`] if those do not decide the rounding. Returns
+    /// [`Error::Inexact`] if even that does not suffice, which takes an exact result within `10^-33` of a
+    /// rounding boundary, and an error for values less than one.
+    pub const fn ln_rounded<R: RoundingPolicy>(self) -> Result<Self, Error> {
+        let unscaled = match ln_correctly_rounded(self.0, S::SCALE, first_precision(S::SCALE), R::MODE) {
+            Err(Error::Inexact) => ln_correctly_rounded(self.0, S::SCALE, MAX_PRECISION, R::MODE),
+            result => result,
+        };
+        match unscaled {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Computes the natural logarithm from precomputed tables in binary fixed point, without any
+    /// division. Trades the last digits at high scales for latency, see the [module](crate::math) documentation
+    /// for the error bound. Returns an error for values less than one.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// assert_eq!("0.69314718", DecimalU64::<U8>::TWO.ln_fast().unwrap().to_string());
+    /// ```
+    #[inline]
+    pub const fn ln_fast(self) -> Result<Self, Error> {
+        match ln_table(self.0, S::SCALE) {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(err) => Err(err),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{DecimalU64, U0, U6};
+    use crate::round::{Ceil, HalfUp};
+    use crate::{DecimalU64, U0, U6, U8, U12, U17, U18};
     use rstest_macros::rstest;
     use std::str::FromStr;
 
@@ -253,6 +1583,48 @@ mod tests {
         assert!(matches!(err, Err(Error::Overflow)));
     }
 
+    #[rstest]
+    #[case("1.5", "0.405465108108164382")]
+    #[case("1.999999999999999999", "0.693147180559945309")]
+    #[case("2", "0.693147180559945309")]
+    #[case("3.141592653589793238", "1.144729885849400174")]
+    #[case("18.4", "2.912350664614939935")]
+    fn should_ln_at_u18(#[case] input: &str, #[case] expected: &str) {
+        let actual = DecimalU64::<U18>::from_str(input).unwrap().ln().unwrap();
+        let expected = DecimalU64::<U18>::from_str(expected).unwrap();
+        assert!(actual.0.abs_diff(expected.0) <= 24);
+    }
+
+    #[rstest]
+    #[case("0.5", "1.648721270700128147")]
+    #[case("1", "2.718281828459045235")]
+    #[case("2.9", "18.174145369443060943")]
+    fn should_exp_at_u18(#[case] input: &str, #[case] expected: &str) {
+        let actual = DecimalU64::<U18>::from_str(input).unwrap().exp().unwrap();
+        let expected = DecimalU64::<U18>::from_str(expected).unwrap();
+        assert!(actual.0.abs_diff(expected.0) <= 64);
+    }
+
+    #[test]
+    fn should_match_to_last_digit_at_u12() {
+        assert_eq!("0.693147180560", DecimalU64::<U12>::TWO.ln().unwrap().to_string());
+        assert_eq!("2.718281828459", DecimalU64::<U12>::ONE.exp().unwrap().to_string());
+        assert_eq!(
+            "0.693147180559",
+            DecimalU64::<U12>::from_str("1.999999999999")
+                .unwrap()
+                .ln()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn should_error_exp_overflow_at_u18() {
+        let value = DecimalU64::<U18>::from_str("3").unwrap();
+        assert!(matches!(value.exp(), Err(Error::Overflow)));
+    }
+
     fn assert_close_f64(actual: f64, expected: f64, tolerance: f64) {
         let diff = if actual >= expected {
             actual - expected
@@ -288,4 +1660,334 @@ mod tests {
         let expected = f64::from_str(input).unwrap().exp();
         assert_close_f64(actual, expected, 5e-6);
     }
+
+    #[rstest]
+    #[case("1.05", 10, "1.62889462", "1.62889462", "1.62889464")]
+    #[case("0.99", 252, "0.07944542", "0.07944544", "0.07944549")]
+    #[case("2", 10, "1024.00000000", "1024.00000000", "1024.00000000")]
+    #[case("0.00000001", 2, "0.00000000", "0.00000000", "0.00000001")]
+    #[case("123.456", 0, "1.00000000", "1.00000000", "1.00000000")]
+    #[case("0", 0, "1.00000000", "1.00000000", "1.00000000")]
+    #[case("0", 3, "0.00000000", "0.00000000", "0.00000000")]
+    fn should_pow(
+        #[case] base: &str,
+        #[case] exp: u32,
+        #[case] floor: &str,
+        #[case] half_up: &str,
+        #[case] ceil: &str,
+    ) {
+        let base = DecimalU64::<U8>::from_str(base).unwrap();
+        assert_eq!(floor, base.pow(exp).to_string());
+        assert_eq!(floor, base.checked_pow::<Floor>(exp).unwrap().to_string());
+        assert_eq!(half_up, base.checked_pow::<HalfUp>(exp).unwrap().to_string());
+        assert_eq!(ceil, base.checked_pow::<Ceil>(exp).unwrap().to_string());
+    }
+
+    #[test]
+    fn should_error_on_pow_overflow() {
+        let value = DecimalU64::<U8>::from_str("10").unwrap();
+        assert_eq!("10000000000.00000000", value.pow(10).to_string());
+        assert!(matches!(value.checked_pow::<HalfUp>(12), Err(Error::Overflow)));
+        assert!(matches!(DecimalU64::<U8>::MAX.checked_pow::<HalfUp>(u32::MAX), Err(Error::Overflow)));
+    }
+
+    #[test]
+    #[should_panic = "attempt to multiply with overflow"]
+    fn should_panic_on_pow_overflow() {
+        let _ = DecimalU64::<U8>::MAX.pow(2);
+    }
+
+    #[rstest]
+    #[case("0.0625", "0.5", "0.250000")]
+    #[case("2", "0.5", "1.414214")]
+    #[case("2", "10", "1024.000000")]
+    #[case("10", "2.5", "316.227766")]
+    #[case("0.5", "3", "0.125000")]
+    #[case("0.001", "100", "0.000000")]
+    #[case("1", "7.3", "1.000000")]
+    #[case("0", "2", "0.000000")]
+    #[case("3", "0", "1.000000")]
+    fn should_powd(#[case] base: &str, #[case] exponent: &str, #[case] expected: &str) {
+        let base = DecimalU64::<U6>::from_str(base).unwrap();
+        let exponent = DecimalU64::<U6>::from_str(exponent).unwrap();
+        assert_eq!(expected, base.powd(exponent).unwrap().to_string());
+    }
+
+    #[test]
+    fn should_powd_at_high_scale() {
+        let two = DecimalU64::<U18>::from_str("2").unwrap();
+        let half = DecimalU64::<U18>::from_str("0.5").unwrap();
+        // sqrt(2) = 1.414213562373095048801...
+        let diff = two.powd(half).unwrap().0.abs_diff(1_414_213_562_373_095_049);
+        assert!(diff <= 64);
+    }
+
+    #[test]
+    fn should_error_on_powd_overflow() {
+        let value = DecimalU64::<U6>::from_str("1000000").unwrap();
+        let exponent = DecimalU64::<U6>::from_str("3").unwrap();
+        assert!(matches!(value.powd(exponent), Err(Error::Overflow)));
+    }
+
+    #[rstest]
+    #[case("2", 2, "1.41421356", "1.41421356", "1.41421357")]
+    #[case("2", 3, "1.25992104", "1.25992105", "1.25992105")]
+    #[case("2", 16, "1.04427378", "1.04427378", "1.04427379")]
+    #[case("0.5", 3, "0.79370052", "0.79370053", "0.79370053")]
+    #[case("0.0625", 2, "0.25000000", "0.25000000", "0.25000000")]
+    #[case("0.00000001", 2, "0.00010000", "0.00010000", "0.00010000")]
+    #[case("184467440737.09551615", 2, "429496.72959999", "429496.72960000", "429496.72960000")]
+    #[case("123.45", 1, "123.45000000", "123.45000000", "123.45000000")]
+    #[case("0", 5, "0.00000000", "0.00000000", "0.00000000")]
+    fn should_nth_root(
+        #[case] value: &str,
+        #[case] n: u32,
+        #[case] floor: &str,
+        #[case] half_up: &str,
+        #[case] ceil: &str,
+    ) {
+        let value = DecimalU64::<U8>::from_str(value).unwrap();
+        assert_eq!(floor, value.nth_root::<Floor>(n).unwrap().to_string());
+        assert_eq!(half_up, value.nth_root::<HalfUp>(n).unwrap().to_string());
+        assert_eq!(ceil, value.nth_root::<Ceil>(n).unwrap().to_string());
+        if n == 2 {
+            assert_eq!(half_up, value.sqrt::<HalfUp>().to_string());
+        }
+    }
+
+    #[test]
+    fn should_nth_root_at_high_scale() {
+        let two = DecimalU64::<U18>::from_str("2").unwrap();
+        assert_eq!("1.414213562373095048", two.sqrt::<Floor>().to_string());
+        assert_eq!("1.414213562373095049", two.sqrt::<HalfUp>().to_string());
+        let half = DecimalU64::<U18>::from_str("0.5").unwrap();
+        assert_eq!("0.905723664263906672", half.nth_root::<HalfUp>(7).unwrap().to_string());
+        let max = DecimalU64::<U0>::MAX;
+        assert_eq!("16", max.nth_root::<HalfUp>(MAX_ROOT_DEGREE).unwrap().to_string());
+    }
+
+    #[test]
+    fn should_sqrt_as_const() {
+        const ROOT: DecimalU64<U6> = DecimalU64::<U6>::new(2_250_000).sqrt::<HalfUp>();
+        assert_eq!("1.500000", ROOT.to_string());
+    }
+
+    #[test]
+    fn should_error_on_invalid_root_degree() {
+        let value = DecimalU64::<U8>::TWO;
+        let zero = Err(Error::InvalidInput(InvalidInputKind::Zero));
+        assert_eq!(zero, value.nth_root::<HalfUp>(0));
+        let too_large = Err(Error::InvalidInput(InvalidInputKind::DegreeTooLarge));
+        assert_eq!(too_large, value.nth_root::<HalfUp>(MAX_ROOT_DEGREE + 1));
+    }
+
+    #[rstest]
+    #[case("1", "0.00000000", "0.00000000", "0.00000000")]
+    #[case("0.95", "-0.05129329", "-0.02227639", "-0.07400058")]
+    #[case("0.5", "-0.69314718", "-0.30103000", "-1.00000000")]
+    #[case("0.01", "-4.60517019", "-2.00000000", "-6.64385619")]
+    #[case("0.00000001", "-18.42068074", "-8.00000000", "-26.57542476")]
+    #[case("2", "0.69314718", "0.30103000", "1.00000000")]
+    #[case("1000", "6.90775528", "3.00000000", "9.96578428")]
+    #[case("184467440737.09551615", "25.94073881", "11.26591972", "37.42457524")]
+    fn should_log(#[case] value: &str, #[case] ln: &str, #[case] log10: &str, #[case] log2: &str) {
+        let value = DecimalU64::<U8>::from_str(value).unwrap();
+        assert_eq!(ln, value.ln_signed().unwrap().to_string());
+        assert_eq!(log10, value.log10().unwrap().to_string());
+        assert_eq!(log2, value.log2().unwrap().to_string());
+        if value >= DecimalU64::<U8>::ONE {
+            assert_eq!(ln, value.ln().unwrap().to_string());
+        }
+    }
+
+    #[rstest]
+    #[case("8", "2", "3.000000")]
+    #[case("0.125", "2", "-3.000000")]
+    #[case("8", "0.5", "-3.000000")]
+    #[case("81", "3", "4.000000")]
+    #[case("2", "10", "0.301030")]
+    fn should_log_base(#[case] value: &str, #[case] base: &str, #[case] expected: &str) {
+        let value = DecimalU64::<U6>::from_str(value).unwrap();
+        let base = DecimalU64::<U6>::from_str(base).unwrap();
+        assert_eq!(expected, value.log_base(base).unwrap().to_string());
+    }
+
+    #[test]
+    fn should_error_on_invalid_log() {
+        let zero = DecimalU64::<U6>::ZERO;
+        let error = Err(Error::InvalidInput(InvalidInputKind::Zero));
+        assert_eq!(error, zero.ln_signed());
+        assert_eq!(error, zero.log10());
+        assert_eq!(error, DecimalU64::<U6>::TWO.log_base(zero));
+        assert_eq!(Err(Error::DivisionByZero), DecimalU64::<U6>::TWO.log_base(DecimalU64::ONE));
+        // ln(10^-18) = -41.4 does not fit into i64 at U18
+        assert_eq!(Err(Error::Overflow), DecimalU64::<U18>::new(1).ln_signed());
+    }
+
+    #[test]
+    fn should_log_at_high_scale() {
+        let value = DecimalU64::<U18>::from_str("0.5").unwrap();
+        assert_eq!("-1.000000000000000000", value.log2().unwrap().to_string());
+        let value = DecimalU64::<U18>::from_str("0.95").unwrap();
+        // ln(0.95) = -0.051293294387550533426...
+        let diff = value.ln_signed().unwrap().0.abs_diff(-51_293_294_387_550_533);
+        assert!(diff <= 25);
+    }
+
+    #[rstest]
+    #[case("0", "1.00000000", "1.00000000", "1.00000000")]
+    #[case("0.00000001", "1.00000001", "1.00000001", "1.00000002")]
+    #[case("0.5", "1.64872127", "1.64872127", "1.64872128")]
+    #[case("1", "2.71828182", "2.71828183", "2.71828183")]
+    #[case("2.30258509", "9.99999997", "9.99999997", "9.99999998")]
+    #[case("10", "22026.46579480", "22026.46579481", "22026.46579481")]
+    fn should_exp_correctly_rounded(
+        #[case] value: &str,
+        #[case] floor: &str,
+        #[case] half_up: &str,
+        #[case] ceil: &str,
+    ) {
+        let value = DecimalU64::<U8>::from_str(value).unwrap();
+        assert_eq!(floor, value.exp_rounded::<Floor>().unwrap().to_string());
+        assert_eq!(half_up, value.exp_rounded::<HalfUp>().unwrap().to_string());
+        assert_eq!(ceil, value.exp_rounded::<Ceil>().unwrap().to_string());
+        assert_eq!(half_up, value.exp_with::<HalfUp>(MAX_PRECISION).unwrap().to_string());
+    }
+
+    #[rstest]
+    #[case("1", "0.00000000", "0.00000000", "0.00000000")]
+    #[case("1.00000001", "0.00000000", "0.00000001", "0.00000001")]
+    #[case("1.5", "0.40546510", "0.40546511", "0.40546511")]
+    #[case("2", "0.69314718", "0.69314718", "0.69314719")]
+    #[case("3.14159265", "1.14472988", "1.14472988", "1.14472989")]
+    #[case("184467440737.09551615", "25.94073881", "25.94073881", "25.94073882")]
+    fn should_ln_correctly_rounded(
+        #[case] value: &str,
+        #[case] floor: &str,
+        #[case] half_up: &str,
+        #[case] ceil: &str,
+    ) {
+        let value = DecimalU64::<U8>::from_str(value).unwrap();
+        assert_eq!(floor, value.ln_rounded::<Floor>().unwrap().to_string());
+        assert_eq!(half_up, value.ln_rounded::<HalfUp>().unwrap().to_string());
+        assert_eq!(ceil, value.ln_rounded::<Ceil>().unwrap().to_string());
+        assert_eq!(half_up, value.ln_with::<HalfUp>(MAX_PRECISION).unwrap().to_string());
+    }
+
+    #[test]
+    fn should_round_correctly_at_u18() {
+        let value = DecimalU64::<U18>::from_str("2.9").unwrap();
+        assert_eq!("18.174145369443060942", value.exp_rounded::<Floor>().unwrap().to_string());
+        assert_eq!("18.174145369443060943", value.exp_rounded::<HalfUp>().unwrap().to_string());
+        let value = DecimalU64::<U18>::from_str("3.141592653589793238").unwrap();
+        assert_eq!("1.144729885849400174", value.ln_rounded::<HalfUp>().unwrap().to_string());
+        assert_eq!("1.144729885849400173", value.ln_rounded::<Floor>().unwrap().to_string());
+    }
+
+    #[test]
+    fn should_round_tiny_arguments_correctly() {
+        let ulp = DecimalU64::<U18>::new(1);
+        assert_eq!("1.000000000000000001", ulp.exp_rounded::<Floor>().unwrap().to_string());
+        assert_eq!("1.000000000000000001", ulp.exp_rounded::<HalfUp>().unwrap().to_string());
+        assert_eq!("1.000000000000000002", ulp.exp_rounded::<Ceil>().unwrap().to_string());
+        let value = DecimalU64::<U18>::ONE + ulp;
+        assert_eq!("0.000000000000000000", value.ln_rounded::<Floor>().unwrap().to_string());
+        assert_eq!("0.000000000000000001", value.ln_rounded::<HalfUp>().unwrap().to_string());
+        assert_eq!("0.000000000000000001", value.ln_rounded::<Ceil>().unwrap().to_string());
+
+        let ulp = DecimalU64::<U17>::new(1);
+        assert_eq!("1.00000000000000001", ulp.exp_rounded::<Floor>().unwrap().to_string());
+        assert_eq!("1.00000000000000002", ulp.exp_rounded::<Ceil>().unwrap().to_string());
+        let value = DecimalU64::<U17>::ONE + ulp;
+        assert_eq!("0.00000000000000000", value.ln_rounded::<Floor>().unwrap().to_string());
+        assert_eq!("0.00000000000000001", value.ln_rounded::<Ceil>().unwrap().to_string());
+    }
+
+    #[test]
+    fn should_decide_rounding_near_zero_at_u18() {
+        // exp(x) - 1 - x and x - ln(1 + x) fall below the error bound of the working precision here
+        for unscaled in 1..2000 {
+            let value = DecimalU64::<U18>::new(unscaled);
+            let (floor, ceil) = (value.exp_rounded::<Floor>().unwrap(), value.exp_rounded::<Ceil>().unwrap());
+            assert_eq!(DecimalU64::ONE + value, floor);
+            assert_eq!(floor + DecimalU64::new(1), ceil);
+            let value = DecimalU64::<U18>::ONE + value;
+            let (floor, ceil) = (value.ln_rounded::<Floor>().unwrap(), value.ln_rounded::<Ceil>().unwrap());
+            assert_eq!(unscaled - 1, floor.0);
+            assert_eq!(unscaled, ceil.0);
+        }
+    }
+
+    #[test]
+    fn should_error_when_precision_does_not_suffice() {
+        let value = DecimalU64::<U8>::from_str("1.5").unwrap();
+        // the error bound of the working result spans several units at the scale
+        assert_eq!(Err(Error::Inexact), value.exp_with::<HalfUp>(8));
+        assert_eq!(Err(Error::Inexact), value.ln_with::<HalfUp>(9));
+        assert_eq!(Err(Error::Inexact), value.ln_with::<HalfUp>(6));
+        assert_eq!(Err(Error::Overflow), value.exp_with::<HalfUp>(MAX_PRECISION + 1));
+        assert_eq!(Err(Error::Inexact), DecimalU64::<U0>::ONE.exp_with::<HalfUp>(0));
+    }
+
+    #[test]
+    fn should_error_on_invalid_rounded_input() {
+        let value = DecimalU64::<U8>::from_str("0.5").unwrap();
+        let error = Err(Error::InvalidInput(InvalidInputKind::LessThanOne));
+        assert_eq!(error, value.ln_rounded::<HalfUp>());
+        assert_eq!(error, value.ln_fast());
+        let value = DecimalU64::<U8>::from_str("41.5").unwrap();
+        assert_eq!(Err(Error::Overflow), value.exp_rounded::<HalfUp>());
+        assert_eq!(Err(Error::Overflow), value.exp_fast());
+        assert_eq!(Err(Error::Overflow), DecimalU64::<U0>::MAX.exp_fast());
+    }
+
+    #[rstest]
+    #[case("0")]
+    #[case("0.00000001")]
+    #[case("0.5")]
+    #[case("1")]
+    #[case("2.30258509")]
+    #[case("10")]
+    #[case("20")]
+    fn should_exp_fast(#[case] value: &str) {
+        let value = DecimalU64::<U8>::from_str(value).unwrap();
+        let expected = value.exp_rounded::<HalfUp>().unwrap();
+        assert_eq!(expected, value.exp_fast().unwrap());
+    }
+
+    #[rstest]
+    #[case("1")]
+    #[case("1.00000001")]
+    #[case("1.5")]
+    #[case("2")]
+    #[case("3.14159265")]
+    #[case("184467440737.09551615")]
+    fn should_ln_fast(#[case] value: &str) {
+        let value = DecimalU64::<U8>::from_str(value).unwrap();
+        let expected = value.ln_rounded::<HalfUp>().unwrap();
+        assert_eq!(expected, value.ln_fast().unwrap());
+    }
+
+    #[rstest]
+    #[case("0.000000000000000001")]
+    #[case("0.693147180559945309")]
+    #[case("1")]
+    #[case("2.9")]
+    fn should_exp_fast_within_bound_at_u18(#[case] value: &str) {
+        let value = DecimalU64::<U18>::from_str(value).unwrap();
+        let expected = value.exp_rounded::<HalfUp>().unwrap();
+        assert!(value.exp_fast().unwrap().0.abs_diff(expected.0) <= 8);
+    }
+
+    #[rstest]
+    #[case("1.000000000000000001")]
+    #[case("1.5")]
+    #[case("2")]
+    #[case("7.389056098930650227")]
+    #[case("18.4")]
+    fn should_ln_fast_within_bound_at_u18(#[case] value: &str) {
+        let value = DecimalU64::<U18>::from_str(value).unwrap();
+        let expected = value.ln_rounded::<HalfUp>().unwrap();
+        assert!(value.ln_fast().unwrap().0.abs_diff(expected.0) <= 1);
+    }
 }
@@ -1,50 +1,388 @@
-use crate::{DecimalU64, ScaleMetrics};
+use crate::error::Error;
+use crate::{DecimalI64, DecimalU64, SCALE_FACTORS, ScaleMetrics};
 
+/// Direction in which the dropped digits of a value are resolved.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Ties and above move away from zero.
+    HalfUp,
+    /// Towards negative infinity.
+    Floor,
+    /// Towards positive infinity.
+    Ceil,
+    /// Ties go to the even neighbour, also known as banker's rounding.
+    HalfEven,
+    /// Ties move towards zero.
+    HalfDown,
+    /// Ties go to the odd neighbour.
+    HalfOdd,
+    /// Towards zero.
+    Down,
+    /// Away from zero.
+    Up,
+}
+
+/// Policy selecting how values are rounded. Tick rounding goes through [`Self::round`], while the
+/// arithmetic and the signed rounding apply [`Self::MODE`] since they cannot call trait methods in a
+/// `const` context.
 pub trait RoundingPolicy {
+    /// Mode used wherever the policy has to be applied in a `const` context. Defaults to truncation like
+    /// the plain operators, custom policies should override it with the closest mode.
+    const MODE: RoundingMode = RoundingMode::Down;
+
+    /// Rounds `value` to a multiple of `tick_size`.
+    ///
+    /// # Panics
+    /// Panics if `tick_size` is zero or if the rounded value does not fit, see [`Self::checked_round`].
     fn round<S: ScaleMetrics + Copy>(value: DecimalU64<S>, tick_size: DecimalU64<S>) -> DecimalU64<S>;
+
+    /// Fallible counterpart of [`Self::round`]. Returns [`Error::DivisionByZero`] if `tick_size` is zero
+    /// and [`Error::Overflow`] if the rounded value does not fit. The default only rejects a zero tick and
+    /// defers to [`Self::round`] otherwise.
+    #[inline]
+    fn checked_round<S: ScaleMetrics + Copy>(
+        value: DecimalU64<S>,
+        tick_size: DecimalU64<S>,
+    ) -> Result<DecimalU64<S>, Error> {
+        if tick_size.0 == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Self::round(value, tick_size))
+    }
 }
 
-///  Round‑half‑up (“.5 → up”), e.g. 0.125 at tick 0.01 → 0.13.
-pub struct HalfUp;
+/// Implements [`RoundingPolicy`] for one of the provided policies on top of the shared tick rounding.
+macro_rules! impl_rounding_policy {
+    ($policy:ident) => {
+        impl RoundingPolicy for $policy {
+            const MODE: RoundingMode = RoundingMode::$policy;
 
-impl RoundingPolicy for HalfUp {
-    #[inline]
-    fn round<S: ScaleMetrics + Copy>(value: DecimalU64<S>, tick_size: DecimalU64<S>) -> DecimalU64<S> {
-        let half_tick = tick_size.0 / 2 + (tick_size.0 % 2);
-        DecimalU64::new(((value.0 + half_tick) / tick_size.0) * tick_size.0)
+            #[inline]
+            fn round<S: ScaleMetrics + Copy>(value: DecimalU64<S>, tick_size: DecimalU64<S>) -> DecimalU64<S> {
+                round_to_tick(Self::MODE, value, tick_size)
+            }
+
+            #[inline]
+            fn checked_round<S: ScaleMetrics + Copy>(
+                value: DecimalU64<S>,
+                tick_size: DecimalU64<S>,
+            ) -> Result<DecimalU64<S>, Error> {
+                checked_round_to_tick(Self::MODE, value, tick_size)
+            }
+        }
+    };
+}
+
+/// Returns `true` if the truncated magnitude `quotient` has to be incremented by one, given whether
+/// it is `odd`, the dropped `remainder` out of `divisor` and the sign of the value being rounded.
+#[inline]
+pub(crate) const fn round_up(mode: RoundingMode, odd: bool, remainder: u128, divisor: u128, negative: bool) -> bool {
+    if remainder == 0 {
+        return false;
+    }
+    let above_half = remainder > divisor - remainder;
+    let tie = remainder == divisor - remainder;
+    match mode {
+        RoundingMode::HalfUp => above_half || tie,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceil => !negative,
+        RoundingMode::HalfEven => above_half || (tie && odd),
+        RoundingMode::HalfDown => above_half,
+        RoundingMode::HalfOdd => above_half || (tie && !odd),
+        RoundingMode::Down => false,
+        RoundingMode::Up => true,
+    }
+}
+
+/// Divides `numerator` by `divisor` and rounds the quotient to a whole number according to `mode`. Used
+/// to drop the extra digits of an intermediate result and land on the last digit of the value's own scale.
+#[inline]
+pub(crate) const fn div_rounded(numerator: u128, divisor: u128, mode: RoundingMode) -> u128 {
+    let quotient = numerator / divisor;
+    if round_up(mode, quotient & 1 == 1, numerator % divisor, divisor, false) {
+        quotient + 1
+    } else {
+        quotient
     }
 }
 
+///  Round‑half‑up (“.5 → up”), e.g. 0.125 at tick 0.01 → 0.13.
+pub struct HalfUp;
+
+impl_rounding_policy!(HalfUp);
+
 /// Always down, e.g. 0.129 at tick 0.01 → 0.12.
 pub struct Floor;
 
-impl RoundingPolicy for Floor {
-    #[inline]
-    fn round<S: ScaleMetrics + Copy>(value: DecimalU64<S>, tick_size: DecimalU64<S>) -> DecimalU64<S> {
-        DecimalU64::new((value.0 / tick_size.0) * tick_size.0)
-    }
-}
+impl_rounding_policy!(Floor);
 
 /// Always up (if not exact), e.g. 0.121 at tick 0.01 → 0.13.
 pub struct Ceil;
 
-impl RoundingPolicy for Ceil {
-    #[allow(clippy::manual_div_ceil)]
-    fn round<S: ScaleMetrics + Copy>(value: DecimalU64<S>, tick_size: DecimalU64<S>) -> DecimalU64<S> {
-        DecimalU64::new(((value.0 + tick_size.0 - 1) / tick_size.0) * tick_size.0)
+impl_rounding_policy!(Ceil);
+
+/// Round-half-even (banker's rounding), e.g. 0.125 at tick 0.01 → 0.12 and 0.135 → 0.14.
+pub struct HalfEven;
+
+impl_rounding_policy!(HalfEven);
+
+/// Round-half-down (".5 → down"), e.g. 0.125 at tick 0.01 → 0.12 and 0.1251 → 0.13.
+pub struct HalfDown;
+
+impl_rounding_policy!(HalfDown);
+
+/// Round-half-odd, e.g. 0.125 at tick 0.01 → 0.13 and 0.135 → 0.13.
+pub struct HalfOdd;
+
+impl_rounding_policy!(HalfOdd);
+
+/// Always towards zero, e.g. -0.129 at tick 0.01 → -0.12. Same as [`Floor`] for unsigned values.
+pub struct Down;
+
+impl_rounding_policy!(Down);
+
+/// Always away from zero (if not exact), e.g. -0.121 at tick 0.01 → -0.13. Same as [`Ceil`] for unsigned
+/// values.
+pub struct Up;
+
+impl_rounding_policy!(Up);
+
+/// Rounds `value` to a multiple of `tick_size`, resolving the remainder by `mode` and panicking on a zero
+/// tick or on overflow.
+#[inline]
+fn round_to_tick<S: ScaleMetrics>(mode: RoundingMode, value: DecimalU64<S>, tick_size: DecimalU64<S>) -> DecimalU64<S> {
+    match checked_round_to_tick(mode, value, tick_size) {
+        Ok(value) => value,
+        Err(Error::DivisionByZero) => panic!("Division by zero"),
+        Err(_) => panic!("attempt to round with overflow"),
+    }
+}
+
+/// Rounds `value` to a multiple of `tick_size`, resolving the remainder by `mode`. Checks for a zero tick
+/// and for overflow when rounding up.
+#[inline]
+const fn checked_round_to_tick<S: ScaleMetrics>(
+    mode: RoundingMode,
+    value: DecimalU64<S>,
+    tick_size: DecimalU64<S>,
+) -> Result<DecimalU64<S>, Error> {
+    if tick_size.0 == 0 {
+        return Err(Error::DivisionByZero);
+    }
+    let quotient = match div_rounded_u64(value.0, tick_size.0, mode) {
+        Some(value) => value,
+        None => return Err(Error::Overflow),
+    };
+    match quotient.checked_mul(tick_size.0) {
+        Some(value) => Ok(DecimalU64::new(value)),
+        None => Err(Error::Overflow),
     }
 }
 
 impl<S: ScaleMetrics + Copy> DecimalU64<S> {
+    /// Rounds to a multiple of `tick_size` according to `R`.
+    ///
+    /// # Panics
+    /// Panics if `tick_size` is zero or if the rounded value does not fit, see [`Self::checked_round`].
     pub fn round<R: RoundingPolicy>(self, tick_size: DecimalU64<S>) -> DecimalU64<S> {
         R::round(self, tick_size)
     }
+
+    /// Rounds to a multiple of `tick_size` according to `R`. Returns [`Error::DivisionByZero`] if
+    /// `tick_size` is zero and [`Error::Overflow`] if the rounded value does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::error::Error;
+    /// use decimal64::round::{Ceil, Floor};
+    /// use decimal64::{DecimalU64, U8};
+    ///
+    /// let max = DecimalU64::<U8>::MAX;
+    /// assert_eq!(Err(Error::Overflow), max.checked_round::<Ceil>(DecimalU64::ONE));
+    /// assert_eq!("184467440737.00000000", max.checked_round::<Floor>(DecimalU64::ONE).unwrap().to_string());
+    /// assert_eq!(Err(Error::DivisionByZero), max.checked_round::<Floor>(DecimalU64::ZERO));
+    /// ```
+    pub fn checked_round<R: RoundingPolicy>(self, tick_size: DecimalU64<S>) -> Result<DecimalU64<S>, Error> {
+        R::checked_round(self, tick_size)
+    }
+}
+
+/// Divides `value` by `factor` and rounds the quotient to a whole number according to `mode`, returning
+/// `None` if rounding up does not fit.
+#[inline]
+const fn div_rounded_u64(value: u64, factor: u64, mode: RoundingMode) -> Option<u64> {
+    let quotient = value / factor;
+    if round_up(mode, quotient & 1 == 1, (value % factor) as u128, factor as u128, false) {
+        quotient.checked_add(1)
+    } else {
+        Some(quotient)
+    }
+}
+
+impl<S: ScaleMetrics> DecimalU64<S> {
+    /// Rounds to `dp` decimal places according to `R`, keeping the scale. Values with no more than `dp`
+    /// decimal places are returned unchanged. Returns [`Error::Overflow`] if rounding up does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::{HalfEven, HalfUp};
+    /// use decimal64::{DecimalU64, U4};
+    ///
+    /// let price = DecimalU64::<U4>::from_str("1.2250").unwrap();
+    /// assert_eq!("1.2300", price.round_dp::<HalfUp>(2).unwrap().to_string());
+    /// assert_eq!("1.2200", price.round_dp::<HalfEven>(2).unwrap().to_string());
+    /// ```
+    pub const fn round_dp<R: RoundingPolicy>(self, dp: u8) -> Result<Self, Error> {
+        if dp >= S::SCALE {
+            return Ok(self);
+        }
+        let factor = SCALE_FACTORS[(S::SCALE - dp) as usize];
+        let quotient = match div_rounded_u64(self.0, factor, R::MODE) {
+            Some(value) => value,
+            None => return Err(Error::Overflow),
+        };
+        match quotient.checked_mul(factor) {
+            Some(value) => Ok(Self::new(value)),
+            None => Err(Error::Overflow),
+        }
+    }
+
+    /// Drops the fractional part, keeping the scale.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// assert_eq!("12.00", DecimalU64::<U2>::from_str("12.34").unwrap().trunc().to_string());
+    /// ```
+    #[inline]
+    pub const fn trunc(self) -> Self {
+        Self::new(self.0 - self.0 % S::SCALE_FACTOR)
+    }
+
+    /// Returns the fractional part, so that `trunc() + fract()` equals this decimal.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// assert_eq!("0.34", DecimalU64::<U2>::from_str("12.34").unwrap().fract().to_string());
+    /// ```
+    #[inline]
+    pub const fn fract(self) -> Self {
+        Self::new(self.0 % S::SCALE_FACTOR)
+    }
+
+    /// Returns the largest integer not greater than this decimal.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// assert_eq!(12, DecimalU64::<U2>::from_str("12.34").unwrap().floor_to_int());
+    /// ```
+    #[inline]
+    pub const fn floor_to_int(self) -> u64 {
+        self.0 / S::SCALE_FACTOR
+    }
+
+    /// Returns the smallest integer not less than this decimal. Always fits, as any fractional part leaves
+    /// room for one more integer.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU64, U2};
+    ///
+    /// assert_eq!(13, DecimalU64::<U2>::from_str("12.34").unwrap().ceil_to_int());
+    /// assert_eq!(12, DecimalU64::<U2>::from_str("12").unwrap().ceil_to_int());
+    /// ```
+    #[inline]
+    pub const fn ceil_to_int(self) -> u64 {
+        self.0.div_ceil(S::SCALE_FACTOR)
+    }
+
+    /// Rescales this decimal to a different scale like [`Self::rescale`], resolving the digits dropped on
+    /// downscale with `R` instead of always rounding half-up. Returns [`Error::Overflow`] if the result does
+    /// not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::round::{Floor, HalfEven};
+    /// use decimal64::{DecimalU64, U2, U4};
+    ///
+    /// let amount = DecimalU64::<U4>::from_str("1.2050").unwrap();
+    /// assert_eq!("1.20", amount.rescale_with::<U2, HalfEven>().unwrap().to_string());
+    /// assert_eq!("1.20", amount.rescale_with::<U2, Floor>().unwrap().to_string());
+    /// ```
+    pub const fn rescale_with<T: ScaleMetrics, R: RoundingPolicy>(&self) -> Result<DecimalU64<T>, Error> {
+        if T::SCALE >= S::SCALE {
+            // upscale
+            let factor = SCALE_FACTORS[(T::SCALE - S::SCALE) as usize];
+            match self.0.checked_mul(factor) {
+                Some(value) => Ok(DecimalU64::new(value)),
+                None => Err(Error::Overflow),
+            }
+        } else {
+            // downscale
+            let factor = SCALE_FACTORS[(S::SCALE - T::SCALE) as usize];
+            match div_rounded_u64(self.0, factor, R::MODE) {
+                Some(value) => Ok(DecimalU64::new(value)),
+                None => Err(Error::Overflow),
+            }
+        }
+    }
+}
+
+impl<S: ScaleMetrics> DecimalI64<S> {
+    /// Rounds to a multiple of `tick_size`. `HalfUp` resolves ties away from zero and `HalfDown` towards
+    /// zero, `HalfEven` and `HalfOdd` pick the even or odd multiple, `Floor` and `Ceil` round towards
+    /// negative and positive infinity, while `Down` and `Up` round towards and away from zero.
+    ///
+    /// # Panics
+    /// Panics if `tick_size` is zero or if the rounded value does not fit, see [`Self::checked_round`].
+    pub fn round<R: RoundingPolicy>(self, tick_size: DecimalU64<S>) -> DecimalI64<S> {
+        match self.checked_round::<R>(tick_size) {
+            Ok(value) => value,
+            Err(Error::DivisionByZero) => panic!("Division by zero"),
+            Err(_) => panic!("attempt to round with overflow"),
+        }
+    }
+
+    /// Rounds to a multiple of `tick_size` like [`Self::round`]. Returns [`Error::DivisionByZero`] if
+    /// `tick_size` is zero and [`Error::Overflow`] if the rounded value does not fit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::error::Error;
+    /// use decimal64::round::Ceil;
+    /// use decimal64::{DecimalI64, DecimalU64, U8};
+    ///
+    /// let max = DecimalI64::<U8>::MAX;
+    /// assert_eq!(Err(Error::Overflow), max.checked_round::<Ceil>(DecimalU64::new(10)));
+    /// assert_eq!(Err(Error::DivisionByZero), max.checked_round::<Ceil>(DecimalU64::ZERO));
+    /// ```
+    pub const fn checked_round<R: RoundingPolicy>(self, tick_size: DecimalU64<S>) -> Result<DecimalI64<S>, Error> {
+        if tick_size.0 == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        let negative = self.0 < 0;
+        let magnitude = self.0.unsigned_abs();
+        let mut quotient = magnitude / tick_size.0;
+        let remainder = magnitude % tick_size.0;
+        if round_up(R::MODE, quotient & 1 == 1, remainder as u128, tick_size.0 as u128, negative) {
+            quotient += 1;
+        }
+        match quotient.checked_mul(tick_size.0) {
+            Some(rounded) => DecimalI64::from_parts(negative, rounded),
+            None => Err(Error::Overflow),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::U8;
+    use crate::{U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16, U17, U18};
     use rstest_macros::rstest;
 
     #[rstest]
@@ -63,6 +401,11 @@ mod tests {
     #[case("1.0563", "0.05", "1.05000000")]
     #[case("1.0666", "0.05", "1.05000000")]
     #[case("1.075", "0.05", "1.10000000")]
+    #[case("0.00000001", "0.00000003", "0.00000000")]
+    #[case("0.00000002", "0.00000003", "0.00000003")]
+    #[case("0.00000004", "0.00000003", "0.00000003")]
+    #[case("0.45", "0.3", "0.60000000")]
+    #[case("0.44", "0.3", "0.30000000")]
     fn should_round_using_round_half_up(#[case] value: &str, #[case] tick_size: &str, #[case] expected: &str) {
         assert_eq!(
             expected,
@@ -71,6 +414,14 @@ mod tests {
                 .round::<HalfUp>(DecimalU64::<U8>::from_str(tick_size).unwrap())
                 .to_string()
         );
+        assert_eq!(
+            expected,
+            DecimalU64::<U8>::from_str(value)
+                .unwrap()
+                .checked_round::<HalfUp>(DecimalU64::<U8>::from_str(tick_size).unwrap())
+                .unwrap()
+                .to_string()
+        );
     }
 
     #[rstest]
@@ -88,6 +439,14 @@ mod tests {
                 .round::<Floor>(DecimalU64::<U8>::from_str(tick_size).unwrap())
                 .to_string()
         );
+        assert_eq!(
+            expected,
+            DecimalU64::<U8>::from_str(value)
+                .unwrap()
+                .checked_round::<Floor>(DecimalU64::<U8>::from_str(tick_size).unwrap())
+                .unwrap()
+                .to_string()
+        );
     }
 
     #[rstest]
@@ -104,5 +463,377 @@ mod tests {
                 .round::<Ceil>(DecimalU64::<U8>::from_str(tick_size).unwrap())
                 .to_string()
         );
+        assert_eq!(
+            expected,
+            DecimalU64::<U8>::from_str(value)
+                .unwrap()
+                .checked_round::<Ceil>(DecimalU64::<U8>::from_str(tick_size).unwrap())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[rstest]
+    #[case("0.125", "0.01", "0.12000000", "0.12000000", "0.13000000")]
+    #[case("0.135", "0.01", "0.14000000", "0.13000000", "0.13000000")]
+    #[case("0.1351", "0.01", "0.14000000", "0.14000000", "0.14000000")]
+    #[case("0.1349", "0.01", "0.13000000", "0.13000000", "0.13000000")]
+    #[case("1.075", "0.05", "1.10000000", "1.05000000", "1.05000000")]
+    #[case("1.025", "0.05", "1.00000000", "1.00000000", "1.05000000")]
+    #[case("1.05", "0.05", "1.05000000", "1.05000000", "1.05000000")]
+    #[case("0.00000005", "0.0000001", "0.00000000", "0.00000000", "0.00000010")]
+    fn should_round_ties(
+        #[case] value: &str,
+        #[case] tick_size: &str,
+        #[case] half_even: &str,
+        #[case] half_down: &str,
+        #[case] half_odd: &str,
+    ) {
+        let value = DecimalU64::<U8>::from_str(value).unwrap();
+        let tick_size = DecimalU64::<U8>::from_str(tick_size).unwrap();
+        assert_eq!(half_even, value.round::<HalfEven>(tick_size).to_string());
+        assert_eq!(half_down, value.round::<HalfDown>(tick_size).to_string());
+        assert_eq!(half_odd, value.round::<HalfOdd>(tick_size).to_string());
+    }
+
+    #[rstest]
+    #[case(
+        "0.01",
+        Err(Error::Overflow),
+        Ok("184467440737.09000000"),
+        Err(Error::Overflow),
+        Err(Error::Overflow)
+    )]
+    #[case(
+        "1",
+        Ok("184467440737.00000000"),
+        Ok("184467440737.00000000"),
+        Err(Error::Overflow),
+        Ok("184467440737.00000000")
+    )]
+    #[case(
+        "0.00000001",
+        Ok("184467440737.09551615"),
+        Ok("184467440737.09551615"),
+        Ok("184467440737.09551615"),
+        Ok("184467440737.09551615")
+    )]
+    #[case(
+        "0",
+        Err(Error::DivisionByZero),
+        Err(Error::DivisionByZero),
+        Err(Error::DivisionByZero),
+        Err(Error::DivisionByZero)
+    )]
+    fn should_check_rounding_at_max(
+        #[case] tick_size: &str,
+        #[case] half_up: Result<&str, Error>,
+        #[case] floor: Result<&str, Error>,
+        #[case] ceil: Result<&str, Error>,
+        #[case] half_even: Result<&str, Error>,
+    ) {
+        let max = DecimalU64::<U8>::MAX;
+        let tick_size = DecimalU64::<U8>::from_str(tick_size).unwrap();
+        let parse = |expected: Result<&str, Error>| expected.map(|value| DecimalU64::<U8>::from_str(value).unwrap());
+        assert_eq!(parse(half_up), max.checked_round::<HalfUp>(tick_size));
+        assert_eq!(parse(floor), max.checked_round::<Floor>(tick_size));
+        assert_eq!(parse(ceil), max.checked_round::<Ceil>(tick_size));
+        assert_eq!(parse(half_even), max.checked_round::<HalfEven>(tick_size));
+    }
+
+    #[test]
+    fn should_check_rounding_with_zero_tick() {
+        let one = DecimalU64::<U8>::ONE;
+        assert_eq!(Err(Error::DivisionByZero), one.checked_round::<HalfDown>(DecimalU64::ZERO));
+        assert_eq!(Err(Error::DivisionByZero), one.checked_round::<HalfOdd>(DecimalU64::ZERO));
+        assert_eq!(Err(Error::DivisionByZero), DecimalU64::<U8>::ZERO.checked_round::<Ceil>(DecimalU64::ZERO));
+    }
+
+    #[test]
+    fn should_check_rounding_with_tick_above_value() {
+        let one = DecimalU64::<U8>::ONE;
+        let max = DecimalU64::<U8>::MAX;
+        assert_eq!(Ok(max), one.checked_round::<Ceil>(max));
+        assert_eq!(Ok(DecimalU64::ZERO), one.checked_round::<HalfUp>(max));
+        assert_eq!(Ok(max), max.checked_round::<HalfUp>(max));
+    }
+
+    fn assert_ties<S: ScaleMetrics + Copy>() {
+        let tick_size = DecimalU64::<S>::new(10);
+        // (value, half up, half even, half down, half odd) in units of the last place
+        for (value, half_up, half_even, half_down, half_odd) in [(25, 30, 20, 20, 30), (35, 40, 40, 30, 30)] {
+            let value = DecimalU64::<S>::new(value);
+            assert_eq!(half_up, value.round::<HalfUp>(tick_size).0);
+            assert_eq!(half_even, value.round::<HalfEven>(tick_size).0);
+            assert_eq!(half_down, value.round::<HalfDown>(tick_size).0);
+            assert_eq!(half_odd, value.round::<HalfOdd>(tick_size).0);
+        }
+        // 5 / 2 leaves a tie in the last place at any scale
+        let (five, two) = (DecimalU64::<S>::new(5), DecimalU64::<S>::TWO);
+        assert_eq!(3, five.div_round::<HalfUp>(two).0);
+        assert_eq!(2, five.div_round::<HalfEven>(two).0);
+        assert_eq!(2, five.div_round::<HalfDown>(two).0);
+        assert_eq!(3, five.div_round::<HalfOdd>(two).0);
+        assert_eq!(2, five.div_round::<Down>(two).0);
+        assert_eq!(3, five.div_round::<Up>(two).0);
+    }
+
+    #[test]
+    fn should_round_ties_at_every_scale() {
+        assert_ties::<U0>();
+        assert_ties::<U1>();
+        assert_ties::<U2>();
+        assert_ties::<U3>();
+        assert_ties::<U4>();
+        assert_ties::<U5>();
+        assert_ties::<U6>();
+        assert_ties::<U7>();
+        assert_ties::<U8>();
+        assert_ties::<U9>();
+        assert_ties::<U10>();
+        assert_ties::<U11>();
+        assert_ties::<U12>();
+        assert_ties::<U13>();
+        assert_ties::<U14>();
+        assert_ties::<U15>();
+        assert_ties::<U16>();
+        assert_ties::<U17>();
+        assert_ties::<U18>();
+    }
+
+    #[rstest]
+    #[case("-1.075", "0.05", "-1.10000000")]
+    #[case("-1.0666", "0.05", "-1.05000000")]
+    #[case("-0.0443", "0.1", "0.00000000")]
+    #[case("1.075", "0.05", "1.10000000")]
+    fn should_round_signed_using_round_half_up(#[case] value: &str, #[case] tick_size: &str, #[case] expected: &str) {
+        assert_eq!(
+            expected,
+            DecimalI64::<U8>::from_str(value)
+                .unwrap()
+                .round::<HalfUp>(DecimalU64::<U8>::from_str(tick_size).unwrap())
+                .to_string()
+        );
+    }
+
+    #[rstest]
+    #[case("-0.121", "0.01", "-0.13000000", "-0.12000000", "-0.12000000", "-0.13000000")]
+    #[case("-0.12", "0.01", "-0.12000000", "-0.12000000", "-0.12000000", "-0.12000000")]
+    #[case("0.121", "0.01", "0.12000000", "0.13000000", "0.12000000", "0.13000000")]
+    fn should_round_signed_using_directed_modes(
+        #[case] value: &str,
+        #[case] tick_size: &str,
+        #[case] floor: &str,
+        #[case] ceil: &str,
+        #[case] down: &str,
+        #[case] up: &str,
+    ) {
+        let value = DecimalI64::<U8>::from_str(value).unwrap();
+        let tick_size = DecimalU64::<U8>::from_str(tick_size).unwrap();
+        assert_eq!(floor, value.round::<Floor>(tick_size).to_string());
+        assert_eq!(ceil, value.round::<Ceil>(tick_size).to_string());
+        assert_eq!(down, value.round::<Down>(tick_size).to_string());
+        assert_eq!(up, value.round::<Up>(tick_size).to_string());
+    }
+
+    #[rstest]
+    #[case("1.2345", 2, "1.2300", "1.2300", "1.2400")]
+    #[case("1.2350", 2, "1.2300", "1.2400", "1.2400")]
+    #[case("1.2350", 4, "1.2350", "1.2350", "1.2350")]
+    #[case("1.2350", 6, "1.2350", "1.2350", "1.2350")]
+    #[case("0.9999", 0, "0.0000", "1.0000", "1.0000")]
+    #[case("0", 1, "0.0000", "0.0000", "0.0000")]
+    fn should_round_dp(
+        #[case] value: &str,
+        #[case] dp: u8,
+        #[case] floor: &str,
+        #[case] half_up: &str,
+        #[case] ceil: &str,
+    ) {
+        let value = DecimalU64::<U4>::from_str(value).unwrap();
+        assert_eq!(floor, value.round_dp::<Floor>(dp).unwrap().to_string());
+        assert_eq!(half_up, value.round_dp::<HalfUp>(dp).unwrap().to_string());
+        assert_eq!(ceil, value.round_dp::<Ceil>(dp).unwrap().to_string());
+    }
+
+    #[rstest]
+    #[case("1.2250", 2, "1.2200", "1.2200", "1.2300")]
+    #[case("1.2350", 2, "1.2400", "1.2300", "1.2300")]
+    #[case("1.2351", 2, "1.2400", "1.2400", "1.2400")]
+    #[case("2.5", 0, "2.0000", "2.0000", "3.0000")]
+    #[case("3.5", 0, "4.0000", "3.0000", "3.0000")]
+    fn should_round_dp_ties(
+        #[case] value: &str,
+        #[case] dp: u8,
+        #[case] half_even: &str,
+        #[case] half_down: &str,
+        #[case] half_odd: &str,
+    ) {
+        let value = DecimalU64::<U4>::from_str(value).unwrap();
+        let tick_size = DecimalU64::<U4>::new(10u64.pow(4 - dp as u32));
+        assert_eq!(half_even, value.round_dp::<HalfEven>(dp).unwrap().to_string());
+        assert_eq!(half_down, value.round_dp::<HalfDown>(dp).unwrap().to_string());
+        assert_eq!(half_odd, value.round_dp::<HalfOdd>(dp).unwrap().to_string());
+        assert_eq!(value.round::<HalfEven>(tick_size), value.round_dp::<HalfEven>(dp).unwrap());
+    }
+
+    #[test]
+    fn should_error_if_round_dp_overflows() {
+        let max = DecimalU64::<U4>::MAX;
+        assert_eq!(Err(Error::Overflow), max.round_dp::<Ceil>(0));
+        assert_eq!(Err(Error::Overflow), max.round_dp::<Ceil>(3));
+        assert_eq!("1844674407370955.0000", max.round_dp::<HalfUp>(0).unwrap().to_string());
+        assert_eq!(Ok(DecimalU64::<U0>::MAX), DecimalU64::<U0>::MAX.round_dp::<Ceil>(0));
+    }
+
+    #[rstest]
+    #[case("12.34", "12.00", "0.34", 12, 13)]
+    #[case("12", "12.00", "0.00", 12, 12)]
+    #[case("0.01", "0.00", "0.01", 0, 1)]
+    #[case("0", "0.00", "0.00", 0, 0)]
+    fn should_split_into_integer_and_fraction(
+        #[case] value: &str,
+        #[case] trunc: &str,
+        #[case] fract: &str,
+        #[case] floor: u64,
+        #[case] ceil: u64,
+    ) {
+        let value = DecimalU64::<U2>::from_str(value).unwrap();
+        assert_eq!(trunc, value.trunc().to_string());
+        assert_eq!(fract, value.fract().to_string());
+        assert_eq!(value, value.trunc() + value.fract());
+        assert_eq!(floor, value.floor_to_int());
+        assert_eq!(ceil, value.ceil_to_int());
+    }
+
+    #[test]
+    fn should_convert_max_to_int() {
+        assert_eq!(184467440737095516, DecimalU64::<U2>::MAX.floor_to_int());
+        assert_eq!(184467440737095517, DecimalU64::<U2>::MAX.ceil_to_int());
+        assert_eq!(19, DecimalU64::<U18>::MAX.ceil_to_int());
+        assert_eq!(u64::MAX, DecimalU64::<U0>::MAX.ceil_to_int());
+    }
+
+    #[rstest]
+    #[case("1.2050", "1.20", "1.21", "1.21", "1.20")]
+    #[case("1.2150", "1.21", "1.22", "1.22", "1.22")]
+    #[case("1.2151", "1.21", "1.22", "1.22", "1.22")]
+    #[case("1.2000", "1.20", "1.20", "1.20", "1.20")]
+    fn should_rescale_with(
+        #[case] value: &str,
+        #[case] floor: &str,
+        #[case] half_up: &str,
+        #[case] ceil: &str,
+        #[case] half_even: &str,
+    ) {
+        let value = DecimalU64::<U4>::from_str(value).unwrap();
+        assert_eq!(floor, value.rescale_with::<U2, Floor>().unwrap().to_string());
+        assert_eq!(half_up, value.rescale_with::<U2, HalfUp>().unwrap().to_string());
+        assert_eq!(ceil, value.rescale_with::<U2, Ceil>().unwrap().to_string());
+        assert_eq!(half_even, value.rescale_with::<U2, HalfEven>().unwrap().to_string());
+        assert_eq!(value.rescale::<U2>(), value.rescale_with::<U2, HalfUp>());
+    }
+
+    #[test]
+    fn should_rescale_with_at_bounds() {
+        assert_eq!(Ok(DecimalU64::<U0>::new(19)), DecimalU64::<U18>::MAX.rescale_with::<U0, Ceil>());
+        assert_eq!(Ok(DecimalU64::<U0>::new(18)), DecimalU64::<U18>::MAX.rescale_with::<U0, Floor>());
+        assert_eq!(Err(Error::Overflow), DecimalU64::<U2>::MAX.rescale_with::<U4, Floor>());
+        assert_eq!(
+            "12.3400",
+            DecimalU64::<U2>::from_str("12.34")
+                .unwrap()
+                .rescale_with::<U4, Ceil>()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[rstest]
+    #[case(i64::MAX, 10, Err(Error::Overflow), Ok(i64::MAX - 7))]
+    #[case(i64::MIN, 10, Ok(i64::MIN + 8), Err(Error::Overflow))]
+    #[case(i64::MIN, 1, Ok(i64::MIN), Ok(i64::MIN))]
+    #[case(i64::MAX, i64::MAX as u64, Ok(i64::MAX), Ok(i64::MAX))]
+    #[case(-1, u64::MAX, Ok(0), Err(Error::Overflow))]
+    #[case(1, 0, Err(Error::DivisionByZero), Err(Error::DivisionByZero))]
+    fn should_check_signed_rounding_at_bounds(
+        #[case] value: i64,
+        #[case] tick_size: u64,
+        #[case] ceil: Result<i64, Error>,
+        #[case] floor: Result<i64, Error>,
+    ) {
+        let value = DecimalI64::<U8>::new(value);
+        let tick_size = DecimalU64::<U8>::new(tick_size);
+        assert_eq!(ceil.map(DecimalI64::new), value.checked_round::<Ceil>(tick_size));
+        assert_eq!(floor.map(DecimalI64::new), value.checked_round::<Floor>(tick_size));
+    }
+
+    #[test]
+    fn should_support_custom_policy() {
+        // implements only the required method, like policies written against earlier releases
+        struct Truncate;
+
+        impl RoundingPolicy for Truncate {
+            fn round<S: ScaleMetrics + Copy>(value: DecimalU64<S>, tick_size: DecimalU64<S>) -> DecimalU64<S> {
+                DecimalU64::new(value.0 / tick_size.0 * tick_size.0)
+            }
+        }
+
+        let value = DecimalU64::<U2>::from_str("1.29").unwrap();
+        let tick_size = DecimalU64::<U2>::from_str("0.25").unwrap();
+        assert_eq!(RoundingMode::Down, Truncate::MODE);
+        assert_eq!("1.25", value.round::<Truncate>(tick_size).to_string());
+        assert_eq!(Ok(value.round::<Truncate>(tick_size)), value.checked_round::<Truncate>(tick_size));
+        assert_eq!(Err(Error::DivisionByZero), value.checked_round::<Truncate>(DecimalU64::ZERO));
+        assert_eq!("0.43", value.div_round::<Truncate>(DecimalU64::THREE).to_string());
+    }
+
+    #[test]
+    #[should_panic = "attempt to round with overflow"]
+    fn should_panic_if_round_overflows() {
+        let _ = DecimalU64::<U8>::MAX.round::<Ceil>(DecimalU64::ONE);
+    }
+
+    #[test]
+    #[should_panic = "attempt to round with overflow"]
+    fn should_panic_if_round_half_up_overflows() {
+        let _ = DecimalU64::<U0>::MAX.round::<HalfUp>(DecimalU64::new(10));
+    }
+
+    #[test]
+    #[should_panic = "Division by zero"]
+    fn should_panic_if_round_by_zero() {
+        let _ = DecimalU64::<U8>::ONE.round::<Floor>(DecimalU64::ZERO);
+    }
+
+    #[test]
+    #[should_panic = "attempt to round with overflow"]
+    fn should_panic_if_signed_round_overflows() {
+        let _ = DecimalI64::<U8>::MAX.round::<Ceil>(DecimalU64::new(10));
+    }
+
+    #[test]
+    #[should_panic = "Division by zero"]
+    fn should_panic_if_signed_round_by_zero() {
+        let _ = DecimalI64::<U8>::ONE.round::<HalfUp>(DecimalU64::ZERO);
+    }
+
+    #[rstest]
+    #[case("-0.125", "0.01", "-0.12000000", "-0.12000000", "-0.13000000")]
+    #[case("-0.135", "0.01", "-0.14000000", "-0.13000000", "-0.13000000")]
+    #[case("-0.1351", "0.01", "-0.14000000", "-0.14000000", "-0.14000000")]
+    #[case("0.135", "0.01", "0.14000000", "0.13000000", "0.13000000")]
+    fn should_round_signed_ties(
+        #[case] value: &str,
+        #[case] tick_size: &str,
+        #[case] half_even: &str,
+        #[case] half_down: &str,
+        #[case] half_odd: &str,
+    ) {
+        let value = DecimalI64::<U8>::from_str(value).unwrap();
+        let tick_size = DecimalU64::<U8>::from_str(tick_size).unwrap();
+        assert_eq!(half_even, value.round::<HalfEven>(tick_size).to_string());
+        assert_eq!(half_down, value.round::<HalfDown>(tick_size).to_string());
+        assert_eq!(half_odd, value.round::<HalfOdd>(tick_size).to_string());
     }
 }
@@ -1,79 +1,87 @@
-use crate::{DecimalU64, ScaleMetrics};
+use crate::{DecimalI64, DecimalU64, DecimalU128, ScaleMetrics};
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use std::fmt;
 use std::marker::PhantomData;
 
-impl<SM: ScaleMetrics> Serialize for DecimalU64<SM> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let s = self.to_string();
-        serializer.serialize_str(&s)
-    }
-}
+macro_rules! impl_serde {
+    ($decimal:ident, $visitor:ident) => {
+        impl<SM: ScaleMetrics> Serialize for $decimal<SM> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let s = self.to_string();
+                serializer.serialize_str(&s)
+            }
+        }
 
-struct DecimalVisitor<S> {
-    marker: PhantomData<S>,
-}
+        struct $visitor<S> {
+            marker: PhantomData<S>,
+        }
 
-impl<S: ScaleMetrics> Visitor<'_> for DecimalVisitor<S> {
-    type Value = DecimalU64<S>;
+        impl<S: ScaleMetrics> Visitor<'_> for $visitor<S> {
+            type Value = $decimal<S>;
 
-    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a decimal represented as a string or a floating point number")
-    }
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a decimal represented as a string or a floating point number")
+            }
 
-    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        let s = value.to_string();
-        let decimal = DecimalU64::from_str(&s).map_err(E::custom)?;
-        Ok(decimal)
-    }
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let s = value.to_string();
+                let decimal = $decimal::from_str(&s).map_err(E::custom)?;
+                Ok(decimal)
+            }
 
-    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        let s = value.to_string();
-        let decimal = DecimalU64::from_str(&s).map_err(E::custom)?;
-        Ok(decimal)
-    }
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let s = value.to_string();
+                let decimal = $decimal::from_str(&s).map_err(E::custom)?;
+                Ok(decimal)
+            }
 
-    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        let s = value.to_string();
-        let decimal = DecimalU64::from_str(&s).map_err(E::custom)?;
-        Ok(decimal)
-    }
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let s = value.to_string();
+                let decimal = $decimal::from_str(&s).map_err(E::custom)?;
+                Ok(decimal)
+            }
 
-    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        let decimal = DecimalU64::from_str(value).map_err(E::custom)?;
-        Ok(decimal)
-    }
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let decimal = $decimal::from_str(value).map_err(E::custom)?;
+                Ok(decimal)
+            }
 
-    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        self.visit_str(&value)
-    }
-}
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&value)
+            }
+        }
 
-// Deserialize by using the custom visitor.
-impl<'de, S: ScaleMetrics> Deserialize<'de> for DecimalU64<S> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        deserializer.deserialize_any(DecimalVisitor { marker: PhantomData })
-    }
+        // Deserialize by using the custom visitor.
+        impl<'de, S: ScaleMetrics> Deserialize<'de> for $decimal<S> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_any($visitor { marker: PhantomData })
+            }
+        }
+    };
 }
+
+impl_serde!(DecimalU64, DecimalVisitor);
+impl_serde!(DecimalI64, SignedDecimalVisitor);
+impl_serde!(DecimalU128, WideDecimalVisitor);
@@ -0,0 +1,617 @@
+use crate::error::{Error, InvalidInputKind};
+use crate::macros::strict_op;
+use crate::{DecimalU64, ScaleMetrics};
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Signed fixed point decimal backed by `i64`, sharing the [`ScaleMetrics`] of [`DecimalU64`]. The scale
+/// factor has to fit into `i64`, which limits the scale to 18.
+///
+/// ```compile_fail
+/// use decimal64::{DecimalI64, gen_scale};
+///
+/// gen_scale!(U19, 19);
+/// let _ = DecimalI64::<U19>::ONE;
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct DecimalI64<S>(pub i64, PhantomData<S>);
+
+impl<S: ScaleMetrics> Display for DecimalI64<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // A buffer large enough for our formatted value.
+        let mut buf = [0u8; 64];
+        let len = self.write_to(&mut buf);
+        // Since we know our data is all ASCII, this is safe.
+        let s = unsafe { std::str::from_utf8_unchecked(&buf[..len]) };
+        f.write_str(s)
+    }
+}
+
+impl<S: ScaleMetrics> DecimalI64<S> {
+    /// Rejects scales whose factor does not fit into `i64` on top of the [`DecimalU64`] checks,
+    /// referencing it forces the evaluation.
+    pub(crate) const VALID_SCALE: () = {
+        let () = DecimalU64::<S>::VALID_SCALE;
+        assert!(S::SCALE_FACTOR <= i64::MAX as u64, "scale factor must fit into i64");
+    };
+
+    #[inline]
+    pub const fn new(unscaled: i64) -> Self {
+        let () = Self::VALID_SCALE;
+        Self(unscaled, PhantomData)
+    }
+
+    /// Scales the whole number `n`, failing const evaluation if the result does not fit into `i64`.
+    const fn whole(n: i64) -> Self {
+        let () = Self::VALID_SCALE;
+        match n.checked_mul(S::SCALE_FACTOR as i64) {
+            Some(unscaled) => Self::new(unscaled),
+            None => panic!("constant does not fit into i64 at this scale"),
+        }
+    }
+
+    pub const ZERO: Self = DecimalI64::new(0);
+    pub const ONE: Self = DecimalI64::whole(1);
+    pub const MINUS_ONE: Self = DecimalI64::whole(-1);
+    pub const TWO: Self = DecimalI64::whole(2);
+    pub const THREE: Self = DecimalI64::whole(3);
+    pub const FOUR: Self = DecimalI64::whole(4);
+    pub const FIVE: Self = DecimalI64::whole(5);
+    pub const SIX: Self = DecimalI64::whole(6);
+    pub const SEVEN: Self = DecimalI64::whole(7);
+    pub const EIGHT: Self = DecimalI64::whole(8);
+    pub const NINE: Self = DecimalI64::whole(9);
+    /// Ten does not fit into `i64` at scale 18, so using this constant there fails to compile.
+    ///
+    /// ```compile_fail
+    /// use decimal64::{DecimalI64, U18};
+    ///
+    /// let _ = DecimalI64::<U18>::TEN;
+    /// ```
+    pub const TEN: Self = DecimalI64::whole(10);
+    pub const MAX: Self = DecimalI64::new(i64::MAX);
+    pub const MIN: Self = DecimalI64::new(i64::MIN);
+
+    /// Minimum buffer length required by [`Self::write_to`], one byte more than the unsigned
+    /// counterpart to make room for the sign.
+    pub const REQUIRED_BUFFER_LEN: usize = S::REQUIRED_BUFFER_LEN + 1;
+
+    /// Applies the sign to `magnitude`, returning an error if the result does not fit into `i64`.
+    pub(crate) const fn from_parts(negative: bool, magnitude: u64) -> Result<Self, Error> {
+        if negative {
+            if magnitude > i64::MIN.unsigned_abs() {
+                return Err(Error::Overflow);
+            }
+            Ok(Self::new((magnitude as i64).wrapping_neg()))
+        } else {
+            if magnitude > i64::MAX as u64 {
+                return Err(Error::Overflow);
+            }
+            Ok(Self::new(magnitude as i64))
+        }
+    }
+
+    /// Parses a decimal with an optional leading `-` from an ASCII byte slice.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalI64, U2};
+    ///
+    /// let value = DecimalI64::<U2>::from_slice(b"-12.34").unwrap();
+    /// assert_eq!("-12.34", value.to_string());
+    /// ```
+    #[inline]
+    pub const fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let (negative, digits) = match bytes {
+            [b'-', rest @ ..] => (true, rest),
+            _ => (false, bytes),
+        };
+        match DecimalU64::<S>::from_slice(digits) {
+            Ok(magnitude) => Self::from_parts(negative, magnitude.0),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses a decimal with an optional leading `-` from a UTF-8 string slice.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalI64, U2};
+    ///
+    /// let value = DecimalI64::<U2>::from_str("-12.34").unwrap();
+    /// assert_eq!("-12.34", value.to_string());
+    /// ```
+    pub const fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_slice(s.as_bytes())
+    }
+
+    /// Converts this decimal to `f64`.
+    pub const fn to_f64(self) -> f64 {
+        self.0 as f64 / S::SCALE_FACTOR as f64
+    }
+
+    /// Returns `true` if this decimal is strictly less than zero.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of this decimal.
+    #[inline]
+    pub const fn signum(&self) -> i64 {
+        self.0.signum()
+    }
+
+    /// Returns the magnitude of this decimal, which always fits into [`DecimalU64`].
+    #[inline]
+    pub const fn unsigned_abs(&self) -> DecimalU64<S> {
+        DecimalU64::new(self.0.unsigned_abs())
+    }
+
+    /// Returns the absolute value, or `None` for [`Self::MIN`].
+    #[inline]
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.0.checked_abs() {
+            Some(value) => Some(Self::new(value)),
+            None => None,
+        }
+    }
+
+    /// Negates this decimal, or returns `None` for [`Self::MIN`].
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.0.checked_neg() {
+            Some(value) => Some(Self::new(value)),
+            None => None,
+        }
+    }
+
+    /// Rescales this decimal to a different scale, returning an error on overflow.
+    /// Downscaling rounds half away from zero when fractional digits are dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalI64, U2, U4};
+    ///
+    /// let amount = DecimalI64::<U4>::from_str("-1.2050").unwrap();
+    /// let downscaled = amount.rescale::<U2>().unwrap();
+    /// assert_eq!("-1.21", downscaled.to_string());
+    /// ```
+    pub const fn rescale<T: ScaleMetrics>(&self) -> Result<DecimalI64<T>, Error> {
+        match self.unsigned_abs().rescale::<T>() {
+            Ok(magnitude) => DecimalI64::<T>::from_parts(self.is_negative(), magnitude.0),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes this decimal into `buffer` and returns the number of bytes written. The buffer must
+    /// be at least [`Self::REQUIRED_BUFFER_LEN`] bytes. Negative values are prefixed with `-`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalI64, U2};
+    ///
+    /// let value = DecimalI64::<U2>::from_str("-1.2").unwrap();
+    /// let mut buffer = [0u8; DecimalI64::<U2>::REQUIRED_BUFFER_LEN];
+    /// let len = value.write_to(&mut buffer);
+    /// assert_eq!("-1.20", std::str::from_utf8(&buffer[..len]).unwrap());
+    /// ```
+    pub fn write_to(&self, buffer: &mut [u8]) -> usize {
+        #[cold]
+        #[inline(never)]
+        fn insufficient_buffer_len(len: usize, required: usize) -> ! {
+            panic!("provided buffer length {} is too small, requires at least {} bytes", len, required);
+        }
+
+        let () = Self::VALID_SCALE;
+
+        if Self::REQUIRED_BUFFER_LEN > buffer.len() {
+            insufficient_buffer_len(buffer.len(), Self::REQUIRED_BUFFER_LEN)
+        }
+
+        if self.is_negative() {
+            buffer[0] = b'-';
+            1 + self.unsigned_abs().write_to(&mut buffer[1..])
+        } else {
+            self.unsigned_abs().write_to(buffer)
+        }
+    }
+
+    /// Writes this decimal into `buffer` without trailing fractional zeros.
+    pub fn write_to_trimmed(&self, buffer: &mut [u8]) -> usize {
+        let len = self.write_to(buffer);
+        if S::SCALE == 0 {
+            return len;
+        }
+
+        // the fractional part is always present, so at least the decimal point remains
+        let mut end = len;
+        while buffer[end - 1] == b'0' {
+            end -= 1;
+        }
+        if buffer[end - 1] == b'.' {
+            end -= 1;
+        }
+
+        end
+    }
+
+    /// Multiply two decimals with the same scale, truncating towards zero.
+    #[inline]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // the product of two i64 always fits into i128
+        let product = self.0 as i128 * other.0 as i128;
+        let result = product / S::SCALE_FACTOR as i128;
+        i64::try_from(result).ok().map(Self::new)
+    }
+
+    /// Add two decimals with the same scale.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let sum = self.0.checked_add(other.0)?;
+        Some(Self::new(sum))
+    }
+
+    /// Subtract one decimal from another. Returns `None` if the result is out of range.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let diff = self.0.checked_sub(other.0)?;
+        Some(Self::new(diff))
+    }
+
+    /// Divide one decimal by another using 128-bit arithmetic for the intermediate computation,
+    /// truncating towards zero.
+    #[inline]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0 == 0 {
+            return None;
+        }
+        let dividend = self.0 as i128 * S::SCALE_FACTOR as i128;
+        let quotient = dividend / other.0 as i128;
+        i64::try_from(quotient).ok().map(Self::new)
+    }
+}
+
+impl<S: ScaleMetrics> TryFrom<DecimalU64<S>> for DecimalI64<S> {
+    type Error = Error;
+
+    fn try_from(value: DecimalU64<S>) -> Result<Self, Self::Error> {
+        Self::from_parts(false, value.0)
+    }
+}
+
+impl<S: ScaleMetrics> TryFrom<DecimalI64<S>> for DecimalU64<S> {
+    type Error = Error;
+
+    fn try_from(value: DecimalI64<S>) -> Result<Self, Self::Error> {
+        if value.is_negative() {
+            return Err(Error::InvalidInput(InvalidInputKind::NegativeNumber));
+        }
+        Ok(DecimalU64::new(value.0 as u64))
+    }
+}
+
+impl<S: ScaleMetrics> Mul for DecimalI64<S> {
+    type Output = DecimalI64<S>;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        strict_op!(
+            self.checked_mul(rhs),
+            {
+                let product = self.0 as i128 * rhs.0 as i128;
+                let scale_factor = S::SCALE_FACTOR as i128;
+                Self::new((product / scale_factor) as i64)
+            },
+            "attempt to multiply with overflow"
+        )
+    }
+}
+
+impl<S: ScaleMetrics> Add for DecimalI64<S> {
+    type Output = DecimalI64<S>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        strict_op!(self.checked_add(rhs), Self::new(self.0 + rhs.0), "attempt to add with overflow")
+    }
+}
+
+impl<S: ScaleMetrics> Sub for DecimalI64<S> {
+    type Output = DecimalI64<S>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        strict_op!(self.checked_sub(rhs), Self::new(self.0 - rhs.0), "attempt to subtract with overflow")
+    }
+}
+
+impl<S: ScaleMetrics> Div for DecimalI64<S> {
+    type Output = DecimalI64<S>;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0 {
+            panic!("Division by zero");
+        }
+        strict_op!(
+            self.checked_div(rhs),
+            {
+                let dividend = self.0 as i128 * S::SCALE_FACTOR as i128;
+                let quotient = dividend / (rhs.0 as i128);
+                Self::new(quotient as i64)
+            },
+            "attempt to divide with overflow"
+        )
+    }
+}
+
+impl<S: ScaleMetrics> Neg for DecimalI64<S> {
+    type Output = DecimalI64<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        strict_op!(self.checked_neg(), Self::new(-self.0), "attempt to negate with overflow")
+    }
+}
+
+impl<S: ScaleMetrics> AddAssign for DecimalI64<S> {
+    #[inline]
+    fn add_assign(&mut self, rhs: DecimalI64<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0), self.0 + rhs.0, "attempt to add with overflow");
+    }
+}
+
+impl<'a, S: ScaleMetrics> AddAssign<&'a DecimalI64<S>> for DecimalI64<S> {
+    fn add_assign(&mut self, rhs: &'a DecimalI64<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0), self.0 + rhs.0, "attempt to add with overflow");
+    }
+}
+
+impl<S: ScaleMetrics> AddAssign<DecimalI64<S>> for &mut DecimalI64<S> {
+    #[inline]
+    fn add_assign(&mut self, rhs: DecimalI64<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0), self.0 + rhs.0, "attempt to add with overflow");
+    }
+}
+
+impl<'a, S: ScaleMetrics> AddAssign<&'a DecimalI64<S>> for &'a mut DecimalI64<S> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a DecimalI64<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0), self.0 + rhs.0, "attempt to add with overflow");
+    }
+}
+
+impl<S: ScaleMetrics> SubAssign for DecimalI64<S> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = strict_op!(self.0.checked_sub(rhs.0), self.0 - rhs.0, "attempt to subtract with overflow");
+    }
+}
+
+impl<S: ScaleMetrics> Sum for DecimalI64<S> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut sum = Self::ZERO;
+        for i in iter {
+            sum += i;
+        }
+        sum
+    }
+}
+
+impl<'a, S: ScaleMetrics> Sum<&'a DecimalI64<S>> for DecimalI64<S> {
+    fn sum<I: Iterator<Item = &'a DecimalI64<S>>>(iter: I) -> Self {
+        let mut sum = Self::ZERO;
+        for i in iter {
+            sum += i;
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Error, InvalidInputKind};
+    use crate::{DecimalI64, DecimalU64, U0, U2, U4, U8, U18};
+    use rstest_macros::rstest;
+
+    #[test]
+    fn should_build_constants_at_scale_18() {
+        assert_eq!("1.000000000000000000", DecimalI64::<U18>::ONE.to_string());
+        assert_eq!("-1.000000000000000000", DecimalI64::<U18>::MINUS_ONE.to_string());
+        assert_eq!("9.000000000000000000", DecimalI64::<U18>::NINE.to_string());
+        assert_eq!("9.223372036854775807", DecimalI64::<U18>::MAX.to_string());
+        assert_eq!("-9.223372036854775808", DecimalI64::<U18>::MIN.to_string());
+    }
+
+    #[test]
+    fn should_not_increase_size() {
+        assert_eq!(std::mem::size_of::<i64>(), std::mem::size_of::<DecimalI64<U8>>());
+    }
+
+    #[rstest]
+    #[case("123.45", 12345000000)]
+    #[case("-123.45", -12345000000)]
+    #[case("-0.00000001", -1)]
+    #[case("-0", 0)]
+    #[case("92233720368.54775807", i64::MAX)]
+    #[case("-92233720368.54775808", i64::MIN)]
+    fn should_parse_from_str(#[case] s: &str, #[case] expected: i64) {
+        assert_eq!(expected, DecimalI64::<U8>::from_str(s).unwrap().0);
+    }
+
+    #[rstest]
+    #[case("92233720368.54775808")]
+    #[case("-92233720368.54775809")]
+    fn should_err_if_number_out_of_range(#[case] s: &str) {
+        assert!(matches!(DecimalI64::<U8>::from_str(s), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn should_err_on_misplaced_sign() {
+        assert!(matches!(
+            DecimalI64::<U8>::from_str("1-2"),
+            Err(Error::InvalidInput(InvalidInputKind::InvalidCharacter('-')))
+        ));
+    }
+
+    #[test]
+    fn should_display_to_string() -> anyhow::Result<()> {
+        assert_eq!("-123.450000", DecimalI64::<crate::U6>::from_str("-123.45")?.to_string());
+        assert_eq!("123.45", DecimalI64::<U2>::from_str("123.45")?.to_string());
+        assert_eq!("-0.01", DecimalI64::<U2>::from_str("-0.01")?.to_string());
+        assert_eq!("0.00", DecimalI64::<U2>::from_str("-0")?.to_string());
+        assert_eq!("-10", DecimalI64::<U0>::from_str("-10")?.to_string());
+        assert_eq!("-92233720368.54775808", DecimalI64::<U8>::MIN.to_string());
+        assert_eq!("92233720368.54775807", DecimalI64::<U8>::MAX.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn should_write_to_trimmed() -> anyhow::Result<()> {
+        let mut buffer = [0u8; 32];
+        let len = DecimalI64::<U4>::from_str("-12.3400")?.write_to_trimmed(&mut buffer);
+        assert_eq!("-12.34", std::str::from_utf8(&buffer[..len])?);
+        Ok(())
+    }
+
+    #[test]
+    fn should_write_min_if_buffer_is_of_exact_size() {
+        let mut buffer = [0u8; DecimalI64::<U8>::REQUIRED_BUFFER_LEN];
+        assert_eq!(21, DecimalI64::<U8>::MIN.write_to(&mut buffer));
+        let mut buffer = [0u8; DecimalI64::<U0>::REQUIRED_BUFFER_LEN];
+        assert_eq!(20, DecimalI64::<U0>::MIN.write_to(&mut buffer));
+    }
+
+    #[test]
+    #[should_panic(expected = "provided buffer length 21 is too small, requires at least 22 bytes")]
+    fn should_panic_if_buffer_too_small() {
+        let mut buffer = [0u8; 21];
+        DecimalI64::<U8>::MIN.write_to(&mut buffer);
+    }
+
+    #[rstest]
+    #[case("0.2", "-50000", "-10000.00000000")]
+    #[case("-1.5", "-2", "3.00000000")]
+    #[case("-0.00000001", "0.5", "0.00000000")]
+    fn should_mul(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        let a = DecimalI64::<U8>::from_str(a).unwrap();
+        let b = DecimalI64::<U8>::from_str(b).unwrap();
+        assert_eq!(expected, a.checked_mul(b).unwrap().to_string());
+        assert_eq!(expected, (a * b).to_string());
+    }
+
+    #[rstest]
+    #[case("0.2", "-50000", "-49999.80000000")]
+    #[case("-123.2", "50000", "49876.80000000")]
+    #[case("-1", "-1", "-2.00000000")]
+    fn should_add(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        let a = DecimalI64::<U8>::from_str(a).unwrap();
+        let b = DecimalI64::<U8>::from_str(b).unwrap();
+        assert_eq!(expected, a.checked_add(b).unwrap().to_string());
+        assert_eq!(expected, (a + b).to_string());
+    }
+
+    #[rstest]
+    #[case("0", "0.01", "-0.01000000")]
+    #[case("-50000", "-0.2", "-49999.80000000")]
+    fn should_sub(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        let a = DecimalI64::<U8>::from_str(a).unwrap();
+        let b = DecimalI64::<U8>::from_str(b).unwrap();
+        assert_eq!(expected, a.checked_sub(b).unwrap().to_string());
+        assert_eq!(expected, (a - b).to_string());
+    }
+
+    #[rstest]
+    #[case("-1", "3", "-0.33333333")]
+    #[case("-50000", "-0.2", "250000.00000000")]
+    #[case("0.129", "-0.01", "-12.90000000")]
+    fn should_div(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        let a = DecimalI64::<U8>::from_str(a).unwrap();
+        let b = DecimalI64::<U8>::from_str(b).unwrap();
+        assert_eq!(expected, a.checked_div(b).unwrap().to_string());
+        assert_eq!(expected, (a / b).to_string());
+    }
+
+    #[test]
+    fn should_detect_overflow() {
+        assert!(DecimalI64::<U8>::MAX.checked_add(DecimalI64::new(1)).is_none());
+        assert!(DecimalI64::<U8>::MIN.checked_sub(DecimalI64::new(1)).is_none());
+        assert!(DecimalI64::<U8>::MIN.checked_mul(DecimalI64::MINUS_ONE).is_none());
+        assert!(DecimalI64::<U8>::MIN.checked_div(DecimalI64::MINUS_ONE).is_none());
+        assert!(DecimalI64::<U8>::ONE.checked_div(DecimalI64::ZERO).is_none());
+        assert!(DecimalI64::<U8>::MIN.checked_neg().is_none());
+        assert!(DecimalI64::<U8>::MIN.checked_abs().is_none());
+    }
+
+    #[test]
+    fn should_negate_and_sum() {
+        let values = [DecimalI64::<U2>::ONE, -DecimalI64::<U2>::THREE, DecimalI64::<U2>::FIVE];
+        assert_eq!(DecimalI64::<U2>::THREE, values.iter().sum());
+        assert_eq!(-DecimalI64::<U2>::THREE, values.into_iter().map(|v| -v).sum());
+    }
+
+    #[rstest]
+    #[case("-1.2050", "-1.21")]
+    #[case("-1.2049", "-1.20")]
+    #[case("1.2050", "1.21")]
+    fn should_rescale_down(#[case] input: &str, #[case] expected: &str) {
+        let value = DecimalI64::<U4>::from_str(input).unwrap();
+        assert_eq!(expected, value.rescale::<U2>().unwrap().to_string());
+    }
+
+    #[test]
+    fn should_err_on_rescale_overflow() {
+        assert!(matches!(DecimalI64::<U0>::MIN.rescale::<U2>(), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn should_convert_to_and_from_unsigned() {
+        let value = DecimalU64::<U8>::from_str("123.45").unwrap();
+        let signed = DecimalI64::try_from(value).unwrap();
+        assert_eq!("123.45000000", signed.to_string());
+        assert_eq!(value, DecimalU64::try_from(signed).unwrap());
+
+        assert!(matches!(DecimalI64::try_from(DecimalU64::<U8>::MAX), Err(Error::Overflow)));
+        assert!(matches!(
+            DecimalU64::try_from(DecimalI64::<U8>::MINUS_ONE),
+            Err(Error::InvalidInput(InvalidInputKind::NegativeNumber))
+        ));
+        assert_eq!(DecimalU64::<U8>::new(i64::MIN.unsigned_abs()), DecimalI64::<U8>::MIN.unsigned_abs());
+    }
+
+    #[cfg(feature = "strict")]
+    mod strict {
+        use crate::{DecimalI64, U8};
+
+        type D = DecimalI64<U8>;
+
+        #[test]
+        #[should_panic = "attempt to add with overflow"]
+        fn should_panic_on_add_overflow() {
+            let _ = D::MAX + D::ONE;
+        }
+
+        #[test]
+        #[should_panic = "attempt to subtract with overflow"]
+        fn should_panic_on_sub_assign_overflow() {
+            let mut value = D::MIN;
+            value -= D::ONE;
+        }
+
+        #[test]
+        #[should_panic = "attempt to multiply with overflow"]
+        fn should_panic_on_mul_overflow() {
+            let _ = D::MIN * D::TWO;
+        }
+
+        #[test]
+        #[should_panic = "attempt to negate with overflow"]
+        fn should_panic_on_neg_overflow() {
+            let _ = -D::MIN;
+        }
+    }
+}
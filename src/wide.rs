@@ -0,0 +1,662 @@
+use crate::error::{Error, InvalidInputKind};
+use crate::macros::strict_op;
+use crate::math::POW10_U128;
+use crate::{DecimalU64, SCALE_FACTORS, ScaleMetrics};
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+/// Fixed point decimal backed by `u128`, sharing the [`ScaleMetrics`] of [`DecimalU64`]. Mainly meant
+/// for accumulating notionals that do not fit into [`DecimalU64`].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct DecimalU128<S>(pub u128, PhantomData<S>);
+
+impl<S: ScaleMetrics> Display for DecimalU128<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // A buffer large enough for our formatted value.
+        let mut buf = [0u8; 64];
+        let len = self.write_to(&mut buf);
+        // Since we know our data is all ASCII, this is safe.
+        let s = unsafe { std::str::from_utf8_unchecked(&buf[..len]) };
+        f.write_str(s)
+    }
+}
+
+/// Full 256-bit product of two `u128` values returned as `(high, low)` halves.
+#[inline]
+pub(crate) const fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    // cannot overflow as every summand is at most (2^64 - 1)
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (mid << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+    (high, low)
+}
+
+/// Divides the 256-bit value `(high, low)` by `divisor`, returning the quotient and remainder or `None`
+/// if the quotient does not fit into `u128` or the divisor is zero.
+#[inline]
+pub(crate) const fn wide_div(high: u128, low: u128, divisor: u128) -> Option<(u128, u128)> {
+    if divisor == 0 || high >= divisor {
+        return None;
+    }
+    if high == 0 {
+        return Some((low / divisor, low % divisor));
+    }
+
+    // restoring binary long division, the invariant `remainder < divisor` keeps the quotient in range
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    let mut bit = 128;
+    while bit > 0 {
+        bit -= 1;
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry != 0 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+/// Divides the 256-bit value `(high, low)` by a non-zero `divisor`, keeping the low 128 bits of the
+/// quotient. The part of `high` that is a multiple of `divisor` only contributes to the dropped bits.
+#[inline]
+const fn truncating_wide_div(high: u128, low: u128, divisor: u128) -> u128 {
+    match wide_div(high % divisor, low, divisor) {
+        Some((quotient, _)) => quotient,
+        None => unreachable!(),
+    }
+}
+
+impl<S: ScaleMetrics> DecimalU128<S> {
+    #[inline]
+    pub const fn new(unscaled: u128) -> Self {
+        let () = DecimalU64::<S>::VALID_SCALE;
+        Self(unscaled, PhantomData)
+    }
+
+    pub const ZERO: Self = DecimalU128::new(0);
+    pub const ONE: Self = DecimalU128::new(S::SCALE_FACTOR as u128);
+    pub const TWO: Self = DecimalU128::new(2 * S::SCALE_FACTOR as u128);
+    pub const THREE: Self = DecimalU128::new(3 * S::SCALE_FACTOR as u128);
+    pub const FOUR: Self = DecimalU128::new(4 * S::SCALE_FACTOR as u128);
+    pub const FIVE: Self = DecimalU128::new(5 * S::SCALE_FACTOR as u128);
+    pub const SIX: Self = DecimalU128::new(6 * S::SCALE_FACTOR as u128);
+    pub const SEVEN: Self = DecimalU128::new(7 * S::SCALE_FACTOR as u128);
+    pub const EIGHT: Self = DecimalU128::new(8 * S::SCALE_FACTOR as u128);
+    pub const NINE: Self = DecimalU128::new(9 * S::SCALE_FACTOR as u128);
+    pub const TEN: Self = DecimalU128::new(10 * S::SCALE_FACTOR as u128);
+    pub const MAX: Self = DecimalU128::new(u128::MAX);
+
+    /// Minimum buffer length required by [`Self::write_to`] to fit [`Self::MAX`].
+    pub const REQUIRED_BUFFER_LEN: usize = if S::SCALE == 0 { 39 } else { 40 };
+
+    /// Parses a decimal from an ASCII byte slice.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU128, U8};
+    ///
+    /// let value = DecimalU128::<U8>::from_slice(b"1000000000000.5").unwrap();
+    /// assert_eq!("1000000000000.50000000", value.to_string());
+    /// ```
+    #[inline]
+    pub const fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let mut unscaled: u128 = 0;
+        let mut fractional_part_flag: u8 = 0;
+        let mut scale_counter: u8 = 0;
+        let mut index: usize = 0;
+
+        while index < bytes.len() {
+            let byte = bytes[index];
+            match byte {
+                b'0'..=b'9' => {
+                    let next = match unscaled.checked_mul(10) {
+                        Some(value) => value,
+                        None => return Err(Error::Overflow),
+                    };
+                    let digit = (byte - b'0') as u128;
+                    unscaled = match next.checked_add(digit) {
+                        Some(value) => value,
+                        None => return Err(Error::Overflow),
+                    };
+
+                    scale_counter += fractional_part_flag;
+                }
+                b'.' => fractional_part_flag = 1,
+                other => return Err(Error::InvalidInput(InvalidInputKind::InvalidCharacter(other as char))),
+            }
+
+            index += 1;
+        }
+
+        let remaining_scale = match S::SCALE.checked_sub(scale_counter) {
+            Some(remaining_scale) => remaining_scale,
+            None => return Err(Error::Overflow),
+        };
+        let factor = SCALE_FACTORS[remaining_scale as usize] as u128;
+        let unscaled = match unscaled.checked_mul(factor) {
+            Some(unscaled) => unscaled,
+            None => return Err(Error::Overflow),
+        };
+
+        Ok(Self::new(unscaled))
+    }
+
+    /// Parses a decimal from a UTF-8 string slice.
+    pub const fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_slice(s.as_bytes())
+    }
+
+    /// Converts this decimal to `f64`.
+    pub const fn to_f64(self) -> f64 {
+        self.0 as f64 / S::SCALE_FACTOR as f64
+    }
+
+    /// Rescales this decimal to a different scale, returning an error on overflow.
+    /// Downscaling rounds half-up when fractional digits are dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU128, U2, U4};
+    ///
+    /// let amount = DecimalU128::<U4>::from_str("1.2050").unwrap();
+    /// let downscaled = amount.rescale::<U2>().unwrap();
+    /// assert_eq!("1.21", downscaled.to_string());
+    /// ```
+    pub const fn rescale<T: ScaleMetrics>(&self) -> Result<DecimalU128<T>, Error> {
+        if T::SCALE >= S::SCALE {
+            // upscale
+            let factor = POW10_U128[(T::SCALE - S::SCALE) as usize];
+            let unscaled = match self.0.checked_mul(factor) {
+                Some(value) => value,
+                None => return Err(Error::Overflow),
+            };
+
+            Ok(DecimalU128::<T>::new(unscaled))
+        } else {
+            // downscale
+            let factor = POW10_U128[(S::SCALE - T::SCALE) as usize];
+            let truncated = self.0 / factor;
+            let remainder = self.0 % factor;
+            let mut rounded = truncated;
+            if remainder != 0 && remainder >= factor - remainder {
+                rounded = match truncated.checked_add(1) {
+                    Some(value) => value,
+                    None => return Err(Error::Overflow),
+                };
+            }
+            Ok(DecimalU128::<T>::new(rounded))
+        }
+    }
+
+    /// Split `unscaled` value into integer and fractional parts.
+    #[inline]
+    pub const fn split(&self) -> (u128, u64) {
+        let integer_part = self.0 / S::SCALE_FACTOR as u128;
+        let fractional_part = (self.0 % S::SCALE_FACTOR as u128) as u64;
+        (integer_part, fractional_part)
+    }
+
+    /// Writes this decimal into `buffer` and returns the number of bytes written. The buffer must
+    /// be at least [`Self::REQUIRED_BUFFER_LEN`] bytes. Output includes trailing zeros to match the scale.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use decimal64::{DecimalU128, U2};
+    ///
+    /// let value = DecimalU128::<U2>::from_str("1.2").unwrap();
+    /// let mut buffer = [0u8; DecimalU128::<U2>::REQUIRED_BUFFER_LEN];
+    /// let len = value.write_to(&mut buffer);
+    /// assert_eq!("1.20", std::str::from_utf8(&buffer[..len]).unwrap());
+    /// ```
+    pub fn write_to(&self, buffer: &mut [u8]) -> usize {
+        #[cold]
+        #[inline(never)]
+        fn insufficient_buffer_len(len: usize, required: usize) -> ! {
+            panic!("provided buffer length {} is too small, requires at least {} bytes", len, required);
+        }
+
+        // ensure the provided buffer has enough length to write the max value
+        if Self::REQUIRED_BUFFER_LEN > buffer.len() {
+            insufficient_buffer_len(buffer.len(), Self::REQUIRED_BUFFER_LEN)
+        }
+
+        let (int_part, frac_part) = self.split();
+        let mut pos = 0;
+
+        // Write the integer part.
+        if int_part == 0 {
+            // SAFETY we already checked the destination buffer is of sufficient size
+            unsafe {
+                *buffer.get_unchecked_mut(pos) = b'0';
+            }
+            pos += 1;
+        } else {
+            let mut tmp = int_part;
+            let mut digit_count = 0;
+            while tmp != 0 {
+                digit_count += 1;
+                tmp /= 10;
+            }
+            pos += digit_count;
+            let mut idx = pos;
+            tmp = int_part;
+            while tmp != 0 {
+                idx -= 1;
+                // SAFETY we already checked the destination buffer is of sufficient size
+                unsafe {
+                    *buffer.get_unchecked_mut(idx) = b'0' + (tmp % 10) as u8;
+                }
+                tmp /= 10;
+            }
+        }
+
+        // The fractional part always fits into u64, so reuse the narrow formatting.
+        if S::SCALE > 0 {
+            // SAFETY we already checked the destination buffer is of sufficient size
+            unsafe {
+                *buffer.get_unchecked_mut(pos) = b'.';
+            }
+            pos += 1;
+            let mut divisor = 10u64.pow((S::SCALE - 1) as u32);
+            let mut frac = frac_part;
+            for _ in 0..S::SCALE {
+                let digit = frac / divisor;
+                // SAFETY we already checked the destination buffer is of sufficient size
+                unsafe {
+                    *buffer.get_unchecked_mut(pos) = b'0' + (digit as u8);
+                }
+                pos += 1;
+                frac %= divisor;
+                divisor /= 10;
+            }
+        }
+
+        pos
+    }
+
+    /// Writes this decimal into `buffer` without trailing fractional zeros.
+    pub fn write_to_trimmed(&self, buffer: &mut [u8]) -> usize {
+        let len = self.write_to(buffer);
+        if S::SCALE == 0 {
+            return len;
+        }
+
+        // the fractional part is always present, so at least the decimal point remains
+        let mut end = len;
+        while buffer[end - 1] == b'0' {
+            end -= 1;
+        }
+        if buffer[end - 1] == b'.' {
+            end -= 1;
+        }
+
+        end
+    }
+
+    /// Multiply two decimals with the same scale.
+    /// The product is computed in 256 bits and then scaled down by dividing by `S::SCALE_FACTOR`.
+    /// Returns `None` if the result does not fit.
+    #[inline]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let (high, low) = widening_mul(self.0, other.0);
+        wide_div(high, low, S::SCALE_FACTOR as u128).map(|(quotient, _)| Self::new(quotient))
+    }
+
+    /// Add two decimals with the same scale.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let sum = self.0.checked_add(other.0)?;
+        Some(Self::new(sum))
+    }
+
+    /// Subtract one decimal from another. Returns `None` if underflow occurs.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let diff = self.0.checked_sub(other.0)?;
+        Some(Self::new(diff))
+    }
+
+    /// Divide one decimal by another using 256-bit arithmetic for the intermediate computation.
+    /// Returns `None` on division by zero or if the result does not fit.
+    #[inline]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let (high, low) = widening_mul(self.0, S::SCALE_FACTOR as u128);
+        wide_div(high, low, other.0).map(|(quotient, _)| Self::new(quotient))
+    }
+}
+
+impl<S: ScaleMetrics> From<DecimalU64<S>> for DecimalU128<S> {
+    #[inline]
+    fn from(value: DecimalU64<S>) -> Self {
+        Self::new(value.0 as u128)
+    }
+}
+
+impl<S: ScaleMetrics> TryFrom<DecimalU128<S>> for DecimalU64<S> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: DecimalU128<S>) -> Result<Self, Self::Error> {
+        match u64::try_from(value.0) {
+            Ok(unscaled) => Ok(DecimalU64::new(unscaled)),
+            Err(_) => Err(Error::Overflow),
+        }
+    }
+}
+
+impl<S: ScaleMetrics> Mul for DecimalU128<S> {
+    type Output = DecimalU128<S>;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        strict_op!(
+            self.checked_mul(rhs),
+            {
+                let (high, low) = widening_mul(self.0, rhs.0);
+                Self::new(truncating_wide_div(high, low, S::SCALE_FACTOR as u128))
+            },
+            "attempt to multiply with overflow"
+        )
+    }
+}
+
+impl<S: ScaleMetrics> Add for DecimalU128<S> {
+    type Output = DecimalU128<S>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        strict_op!(self.checked_add(rhs), Self::new(self.0 + rhs.0), "attempt to add with overflow")
+    }
+}
+
+impl<S: ScaleMetrics> Sub for DecimalU128<S> {
+    type Output = DecimalU128<S>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        strict_op!(self.checked_sub(rhs), Self::new(self.0 - rhs.0), "attempt to subtract with overflow")
+    }
+}
+
+impl<S: ScaleMetrics> Div for DecimalU128<S> {
+    type Output = DecimalU128<S>;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0 {
+            panic!("Division by zero");
+        }
+        strict_op!(
+            self.checked_div(rhs),
+            {
+                let (high, low) = widening_mul(self.0, S::SCALE_FACTOR as u128);
+                Self::new(truncating_wide_div(high, low, rhs.0))
+            },
+            "attempt to divide with overflow"
+        )
+    }
+}
+
+impl<S: ScaleMetrics> AddAssign for DecimalU128<S> {
+    #[inline]
+    fn add_assign(&mut self, rhs: DecimalU128<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0), self.0 + rhs.0, "attempt to add with overflow");
+    }
+}
+
+impl<'a, S: ScaleMetrics> AddAssign<&'a DecimalU128<S>> for DecimalU128<S> {
+    fn add_assign(&mut self, rhs: &'a DecimalU128<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0), self.0 + rhs.0, "attempt to add with overflow");
+    }
+}
+
+impl<S: ScaleMetrics> AddAssign<DecimalU64<S>> for DecimalU128<S> {
+    #[inline]
+    fn add_assign(&mut self, rhs: DecimalU64<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0 as u128), self.0 + rhs.0 as u128, "attempt to add with overflow");
+    }
+}
+
+impl<'a, S: ScaleMetrics> AddAssign<&'a DecimalU64<S>> for DecimalU128<S> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a DecimalU64<S>) {
+        self.0 = strict_op!(self.0.checked_add(rhs.0 as u128), self.0 + rhs.0 as u128, "attempt to add with overflow");
+    }
+}
+
+impl<S: ScaleMetrics> SubAssign for DecimalU128<S> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = strict_op!(self.0.checked_sub(rhs.0), self.0 - rhs.0, "attempt to subtract with overflow");
+    }
+}
+
+impl<S: ScaleMetrics> Sum for DecimalU128<S> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut sum = Self::ZERO;
+        for i in iter {
+            sum += i;
+        }
+        sum
+    }
+}
+
+impl<'a, S: ScaleMetrics> Sum<&'a DecimalU128<S>> for DecimalU128<S> {
+    fn sum<I: Iterator<Item = &'a DecimalU128<S>>>(iter: I) -> Self {
+        let mut sum = Self::ZERO;
+        for i in iter {
+            sum += i;
+        }
+        sum
+    }
+}
+
+// Summing fewer than 2^64 values of u64 can never overflow u128.
+impl<S: ScaleMetrics> Sum<DecimalU64<S>> for DecimalU128<S> {
+    fn sum<I: Iterator<Item = DecimalU64<S>>>(iter: I) -> Self {
+        let mut sum = Self::ZERO;
+        for i in iter {
+            sum += i;
+        }
+        sum
+    }
+}
+
+impl<'a, S: ScaleMetrics> Sum<&'a DecimalU64<S>> for DecimalU128<S> {
+    fn sum<I: Iterator<Item = &'a DecimalU64<S>>>(iter: I) -> Self {
+        let mut sum = Self::ZERO;
+        for i in iter {
+            sum += i;
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{DecimalU64, DecimalU128, U0, U2, U4, U8};
+    use rstest_macros::rstest;
+
+    #[test]
+    fn should_parse_and_display() -> anyhow::Result<()> {
+        assert_eq!("123.45000000", DecimalU128::<U8>::from_str("123.45")?.to_string());
+        assert_eq!("0.00000000", DecimalU128::<U8>::from_str("0")?.to_string());
+        assert_eq!("10", DecimalU128::<U0>::from_str("10")?.to_string());
+        assert_eq!("3402823669209384634633746074317.68211455", DecimalU128::<U8>::MAX.to_string());
+        assert_eq!("340282366920938463463374607431768211455", DecimalU128::<U0>::MAX.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn should_err_if_number_too_large() {
+        assert!(matches!(
+            DecimalU128::<U8>::from_str("3402823669209384634633746074317.68211456"),
+            Err(Error::Overflow)
+        ));
+        assert!(matches!(DecimalU128::<U2>::from_str("1.234"), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn should_write_max_if_buffer_is_of_exact_size() {
+        let mut buffer = [0u8; DecimalU128::<U8>::REQUIRED_BUFFER_LEN];
+        assert_eq!(40, DecimalU128::<U8>::MAX.write_to(&mut buffer));
+        let mut buffer = [0u8; DecimalU128::<U0>::REQUIRED_BUFFER_LEN];
+        assert_eq!(39, DecimalU128::<U0>::MAX.write_to(&mut buffer));
+    }
+
+    #[test]
+    fn should_write_to_trimmed() -> anyhow::Result<()> {
+        let mut buffer = [0u8; 64];
+        let len = DecimalU128::<U4>::from_str("12.3400")?.write_to_trimmed(&mut buffer);
+        assert_eq!("12.34", std::str::from_utf8(&buffer[..len])?);
+        let len = DecimalU128::<U4>::from_str("12")?.write_to_trimmed(&mut buffer);
+        assert_eq!("12", std::str::from_utf8(&buffer[..len])?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("0.2", "50000", "10000.00000000")]
+    #[case("1000000000", "1000000000", "1000000000000000000.00000000")]
+    #[case("0.00000001", "0.00000001", "0.00000000")]
+    #[case(
+        "3402823669209384634633746074317.68211455",
+        "1",
+        "3402823669209384634633746074317.68211455"
+    )]
+    #[case(
+        "3402823669209384634633746074317.68211455",
+        "0.5",
+        "1701411834604692317316873037158.84105727"
+    )]
+    fn should_mul(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        let a = DecimalU128::<U8>::from_str(a).unwrap();
+        let b = DecimalU128::<U8>::from_str(b).unwrap();
+        assert_eq!(expected, a.checked_mul(b).unwrap().to_string());
+        assert_eq!(expected, (a * b).to_string());
+    }
+
+    #[rstest]
+    #[case("50000", "0.2", "250000.00000000")]
+    #[case("1", "3", "0.33333333")]
+    #[case("1000000000000000000000", "0.001", "1000000000000000000000000.00000000")]
+    #[case(
+        "3402823669209384634633746074317.68211455",
+        "1",
+        "3402823669209384634633746074317.68211455"
+    )]
+    fn should_div(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        let a = DecimalU128::<U8>::from_str(a).unwrap();
+        let b = DecimalU128::<U8>::from_str(b).unwrap();
+        assert_eq!(expected, a.checked_div(b).unwrap().to_string());
+        assert_eq!(expected, (a / b).to_string());
+    }
+
+    #[test]
+    fn should_detect_overflow() {
+        let max = DecimalU128::<U8>::MAX;
+        assert!(max.checked_mul(DecimalU128::TWO).is_none());
+        assert!(max.checked_add(DecimalU128::new(1)).is_none());
+        assert!(DecimalU128::<U8>::ZERO.checked_sub(DecimalU128::new(1)).is_none());
+        assert!(max.checked_div(DecimalU128::new(1)).is_none());
+        assert!(DecimalU128::<U8>::ONE.checked_div(DecimalU128::ZERO).is_none());
+    }
+
+    #[test]
+    #[should_panic = "Division by zero"]
+    fn should_panic_if_div_by_zero() {
+        let _ = DecimalU128::<U8>::ONE / DecimalU128::ZERO;
+    }
+
+    #[test]
+    fn should_add_and_sub() {
+        let mut value = DecimalU128::<U2>::ONE + DecimalU128::TWO;
+        assert_eq!(DecimalU128::THREE, value);
+        value -= DecimalU128::ONE;
+        assert_eq!(DecimalU128::TWO, value - DecimalU128::ZERO);
+    }
+
+    #[test]
+    fn should_rescale() -> anyhow::Result<()> {
+        let value = DecimalU128::<U4>::from_str("1.2050")?;
+        assert_eq!("1.21", value.rescale::<U2>()?.to_string());
+        assert_eq!("1.20500000", value.rescale::<U8>()?.to_string());
+        assert!(matches!(DecimalU128::<U0>::MAX.rescale::<U2>(), Err(Error::Overflow)));
+        Ok(())
+    }
+
+    #[test]
+    fn should_widen_and_narrow() {
+        let value = DecimalU64::<U8>::from_str("123.45").unwrap();
+        let wide = DecimalU128::from(value);
+        assert_eq!("123.45000000", wide.to_string());
+        assert_eq!(value, DecimalU64::try_from(wide).unwrap());
+        assert_eq!(DecimalU64::<U8>::MAX, DecimalU64::try_from(DecimalU128::from(DecimalU64::<U8>::MAX)).unwrap());
+        assert!(matches!(DecimalU64::try_from(DecimalU128::<U8>::new(u64::MAX as u128 + 1)), Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn should_sum_narrow_values_without_overflow() {
+        let values = vec![DecimalU64::<U8>::MAX; 4];
+        let sum: DecimalU128<U8> = values.iter().sum();
+        assert_eq!(4 * u64::MAX as u128, sum.0);
+        let sum: DecimalU128<U8> = values.into_iter().sum();
+        assert_eq!(4 * u64::MAX as u128, sum.0);
+
+        let values = [DecimalU128::<U8>::ONE, DecimalU128::TWO];
+        assert_eq!(DecimalU128::THREE, values.iter().sum());
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn should_truncate_on_mul_and_div_overflow() {
+        let max = DecimalU128::<U8>::MAX;
+        assert_eq!(DecimalU128::new(u128::MAX - 1), max * DecimalU128::new(200_000_000));
+        assert_eq!(DecimalU128::new(u128::MAX - 99_999_999), max / DecimalU128::new(1));
+    }
+
+    #[cfg(feature = "strict")]
+    mod strict {
+        use crate::{DecimalU64, DecimalU128, U8};
+
+        #[test]
+        #[should_panic = "attempt to multiply with overflow"]
+        fn should_panic_on_mul_overflow() {
+            let _ = DecimalU128::<U8>::MAX * DecimalU128::TWO;
+        }
+
+        #[test]
+        #[should_panic = "attempt to divide with overflow"]
+        fn should_panic_on_div_overflow() {
+            let _ = DecimalU128::<U8>::MAX / DecimalU128::new(1);
+        }
+
+        #[test]
+        #[should_panic = "attempt to add with overflow"]
+        fn should_panic_on_add_assign_overflow() {
+            let mut value = DecimalU128::<U8>::MAX;
+            value += DecimalU64::<U8>::ONE;
+        }
+
+        #[test]
+        #[should_panic = "attempt to subtract with overflow"]
+        fn should_panic_on_sub_overflow() {
+            let _ = DecimalU128::<U8>::ZERO - DecimalU128::ONE;
+        }
+    }
+}
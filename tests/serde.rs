@@ -1,9 +1,9 @@
-use decimal64::{DecimalU64, U8};
-#[cfg(feature = "serde")]
+#![cfg(feature = "serde")]
+
+use decimal64::{DecimalI64, DecimalU64, DecimalU128, U8};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 
-#[cfg(feature = "serde")]
 #[test]
 fn should_deserialize() {
     #[derive(Deserialize, Serialize, Debug)]
@@ -20,3 +20,29 @@ fn should_deserialize() {
     assert_eq!("100.00000000", item.three.to_string());
     assert_eq!("0.50000000", item.four.to_string());
 }
+
+#[test]
+fn should_deserialize_signed() {
+    #[derive(Deserialize, Serialize, Debug)]
+    struct Item {
+        one: DecimalI64<U8>,
+        two: DecimalI64<U8>,
+        three: DecimalI64<U8>,
+        four: DecimalI64<U8>,
+    }
+
+    let item: Item = serde_json::from_reader(File::open("tests/signed.json").unwrap()).unwrap();
+    assert_eq!("-123.45000000", item.one.to_string());
+    assert_eq!("-456.78000000", item.two.to_string());
+    assert_eq!("-100.00000000", item.three.to_string());
+    assert_eq!("0.50000000", item.four.to_string());
+    assert_eq!(r#""-123.45000000""#, serde_json::to_string(&item.one).unwrap());
+}
+
+#[test]
+fn should_round_trip_wide() {
+    let value = DecimalU128::<U8>::from_str("1000000000000000.5").unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(r#""1000000000000000.50000000""#, json);
+    assert_eq!(value, serde_json::from_str::<DecimalU128<U8>>(&json).unwrap());
+}